@@ -1,10 +1,12 @@
 pub mod character;
 pub mod gradient;
 pub mod group;
+pub mod rules;
+pub mod scenario;
+pub mod widgets;
 
 mod dnd;
 mod io;
-mod widgets;
 
 use egui::{Align, Layout};
 
@@ -12,10 +14,12 @@ use io::{IoResponse, IoThread};
 
 use crate::{
     app::{
-        character::Character,
+        character::{Character, Violations},
         dnd::DragOperation,
         group::{CharIndex, Group, GroupAction},
         io::IoRequest,
+        rules::{RuleSet, RulesAction},
+        scenario::{Scenario, ScenarioAction},
     },
     simulator::Simulator,
 };
@@ -27,7 +31,18 @@ pub const EDITOR_WIDTH: f32 = 650.0;
 pub struct App {
     chars_left: Group,
     chars_right: Group,
+    #[serde(default)]
+    rules: RuleSet,
+    #[serde(default)]
+    scenario: Scenario,
 
+    #[serde(skip)]
+    show_rules: bool,
+    #[serde(skip)]
+    show_scenario: bool,
+    /// name and unmet prerequisites of the last loaded char
+    #[serde(skip)]
+    load_violations: Option<(String, Violations)>,
     #[serde(skip)]
     selection: Option<CharSelection>,
     #[serde(skip)]
@@ -68,7 +83,11 @@ impl eframe::App for App {
 
         for io_response in self.io.poll_iter() {
             match io_response {
-                IoResponse::CharLoaded(group_id, new_char) => {
+                IoResponse::CharLoaded(group_id, new_char, violations) => {
+                    if !violations.is_empty() {
+                        let name = new_char.name.as_str().to_owned();
+                        self.load_violations = Some((name, violations));
+                    }
                     let group = match group_id {
                         GroupId::Left => &mut self.chars_left,
                         GroupId::Right => &mut self.chars_right,
@@ -76,6 +95,14 @@ impl eframe::App for App {
                     group.add_char(new_char);
                     log::info!("character successfully loaded");
                 }
+                IoResponse::RulesLoaded(rules) => {
+                    self.rules = rules;
+                    log::info!("rules successfully loaded");
+                }
+                IoResponse::ScenarioLoaded(scenario) => {
+                    self.scenario = scenario;
+                    log::info!("scenario successfully loaded");
+                }
             }
         }
 
@@ -84,8 +111,10 @@ impl eframe::App for App {
         }
 
         self.simulator.update(
-            self.chars_left.clone().into_vec(),
-            self.chars_right.clone().into_vec(),
+            self.chars_left.clone(),
+            self.chars_right.clone(),
+            self.rules.clone(),
+            self.scenario.clone(),
         );
 
         egui::Panel::top("top_panel").show(ui, |ui| {
@@ -96,6 +125,8 @@ impl eframe::App for App {
                 ui.with_layout(Layout::right_to_left(Align::TOP), |ui| {
                     Self::quit_button(ui);
                     self.help_button(ui);
+                    self.rules_button(ui);
+                    self.scenario_button(ui);
                 });
             });
             ui.add_space(2.0);
@@ -122,6 +153,10 @@ impl eframe::App for App {
                 self.draw_group(GroupId::Right, ui);
             });
 
+        self.draw_rules(ui);
+        self.draw_scenario(ui);
+        self.draw_load_violations(ui);
+
         // The central panel the region left after adding other panels - has to come last
         egui::CentralPanel::default().show(ui, |ui| {
             ui.vertical_centered(|ui| {
@@ -265,6 +300,86 @@ impl App {
         ui.add(progress_bar);
     }
 
+    fn rules_button(&mut self, ui: &mut egui::Ui) {
+        let text = egui::RichText::new("⚙").size(24.0);
+        let button = egui::Button::new(text).corner_radius(5.0);
+        let response = ui.add_sized([32.0, 32.0], button).on_hover_ui(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Hausregeln anzeigen");
+            });
+        });
+        if response.clicked() {
+            log::info!("rules button clicked");
+            self.show_rules = !self.show_rules;
+        }
+    }
+
+    fn draw_rules(&mut self, ui: &mut egui::Ui) {
+        let mut action = None;
+        egui::Window::new("Hausregeln")
+            .open(&mut self.show_rules)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                action = self.rules.draw(ui);
+            });
+        match action {
+            Some(RulesAction::Save) => self.io.request(IoRequest::SaveRules(self.rules.clone())),
+            Some(RulesAction::Load) => self.io.request(IoRequest::LoadRules),
+            None => (),
+        }
+    }
+
+    fn scenario_button(&mut self, ui: &mut egui::Ui) {
+        let text = egui::RichText::new("🗺").size(24.0);
+        let button = egui::Button::new(text).corner_radius(5.0);
+        let response = ui.add_sized([32.0, 32.0], button).on_hover_ui(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Szenario anzeigen");
+            });
+        });
+        if response.clicked() {
+            log::info!("scenario button clicked");
+            self.show_scenario = !self.show_scenario;
+        }
+    }
+
+    fn draw_scenario(&mut self, ui: &mut egui::Ui) {
+        let mut action = None;
+        egui::Window::new("Szenario")
+            .open(&mut self.show_scenario)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                action = self.scenario.draw(ui);
+            });
+        match action {
+            Some(ScenarioAction::Save) => {
+                self.io
+                    .request(IoRequest::SaveScenario(self.scenario.clone()));
+            }
+            Some(ScenarioAction::Load) => self.io.request(IoRequest::LoadScenario),
+            None => (),
+        }
+    }
+
+    fn draw_load_violations(&mut self, ui: &mut egui::Ui) {
+        let Some((name, violations)) = &self.load_violations else {
+            return;
+        };
+        let mut open = true;
+        egui::Window::new("Voraussetzungen")
+            .open(&mut open)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ui.label(format!(
+                    "Der geladene Char {name} erfüllt nicht alle Voraussetzungen:"
+                ));
+                violations.draw_warnings(ui);
+            });
+        if !open {
+            self.load_violations = None;
+        }
+    }
+
     fn help_button(&mut self, ui: &mut egui::Ui) {
         ui.with_layout(Layout::right_to_left(Align::TOP), |ui| {
             let text = egui::RichText::new("❓").size(24.0);
@@ -1,22 +1,41 @@
+mod arcane;
 mod armor;
 mod attributes;
 mod bennies;
+mod consumables;
+mod creature;
+mod damage_over_time;
 mod edges;
 mod name;
+mod natural_attacks;
 mod passive_stats;
+mod ranged_weapon;
+mod rank;
 mod skills;
+mod tactics;
 mod weapon;
 
 use passive_stats::PassiveModifiers;
 
+pub use arcane::{Arcane, Spell};
 pub use armor::Armor;
 pub use attributes::{Attribute, Attributes};
 pub use bennies::Bennies;
-pub use edges::{Edge3, Edges};
+pub use consumables::{Consumable, Consumables};
+pub use creature::Creature;
+pub use damage_over_time::DamageOverTime;
+pub use edges::{
+    AttackKind, Aura, Capability, EdgeContext, EdgeHooks, EdgeId, EdgeTrigger, Edges, Initiative,
+    Modifier, Trigger, Violations,
+};
 pub use name::Name;
+pub use natural_attacks::{NaturalAttack, NaturalAttacks};
 pub use passive_stats::PassiveStats;
+pub use ranged_weapon::RangedWeapon;
+pub use rank::Experience;
 pub use skills::{Skill, Skills};
-pub use weapon::Weapon;
+pub use tactics::{Protection, Tactics};
+pub use weapon::{Weapon, WeaponKind};
 
 use super::{
     io::{IoRequest, IoThread},
@@ -33,27 +52,48 @@ trait Drawable {
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Character {
     pub(crate) name: Name,
+    #[serde(default)]
+    pub(crate) experience: Experience,
     pub(crate) passive_modifiers: PassiveModifiers,
+    #[serde(default)]
+    pub(crate) creature: Creature,
     pub(crate) attributes: Attributes,
     pub(crate) skills: Skills,
     pub(crate) armor: Armor,
     pub(crate) weapon: Weapon<false>,
     pub(crate) secondary_weapon: Weapon<true>,
+    #[serde(default)]
+    pub(crate) ranged_weapon: RangedWeapon,
+    #[serde(default)]
+    pub(crate) natural_attacks: NaturalAttacks,
     pub(crate) edges: Edges,
     pub(crate) bennies: Bennies,
+    #[serde(default)]
+    pub(crate) arcane: Arcane,
+    #[serde(default)]
+    pub(crate) consumables: Consumables,
+    #[serde(default)]
+    pub(crate) tactics: Tactics,
 }
 
 impl Character {
     fn drawable_iter(&mut self) -> impl Iterator<Item = &mut dyn Drawable> {
         [
+            &mut self.experience as _,
             &mut self.attributes as _,
             &mut self.skills as _,
             &mut self.passive_modifiers as _,
+            &mut self.creature as _,
             &mut self.armor as _,
             &mut self.weapon as _,
             &mut self.secondary_weapon as _,
+            &mut self.ranged_weapon as _,
+            &mut self.natural_attacks as _,
             &mut self.edges as _,
             &mut self.bennies as _,
+            &mut self.arcane as _,
+            &mut self.consumables as _,
+            &mut self.tactics as _,
         ]
         .into_iter()
     }
@@ -72,6 +112,9 @@ impl Character {
                 egui::containers::ScrollArea::both().show(ui, |ui| {
                     self.draw_buttons(io, ui);
 
+                    let mut passive_stats = PassiveStats::new(self, sim.rules());
+                    let mut violations = Violations::new(self);
+
                     let mut draw = |drawable: &mut dyn Drawable| {
                         widgets::create_frame(ui).show(ui, |ui| {
                             ui.set_width(app::EDITOR_WIDTH * 0.9);
@@ -79,10 +122,11 @@ impl Character {
                         });
                     };
 
-                    let mut passive_stats = PassiveStats::new(self);
-
                     draw(&mut self.name);
                     draw(&mut passive_stats);
+                    if !violations.is_empty() {
+                        draw(&mut violations);
+                    }
 
                     for drawable in self.drawable_iter() {
                         draw(drawable);
@@ -115,6 +159,8 @@ impl Character {
                     text(ui, "Charakter editieren: Auf Charakter der linken oder rechten Gruppe klicken.");
                     text(ui, "Charakter aus Gruppe löschen: Kleines 'x'.");
                     text(ui, "Alle Charaktere einer Gruppe löschen: Mülleimer.");
+                    text(ui, "Gruppenbennies: Gemeinsamer Pool (z.B. des Meisters), aus dem die Wildcards der Gruppe schöpfen.");
+                    text(ui, "Gemeinsame Initiative: Alle Statisten der Gruppe ziehen eine Karte und handeln zusammen.");
 
                     header(ui, "Bedienung Editor");
                     text(ui, "Charakter speichern: Save Button.");
@@ -122,9 +168,29 @@ impl Character {
                     text(ui, "Setzen der Werte mit Slidern und Buttons.");
                     text(ui, "Rechts daneben: Änderung der Gewinnchance für die linke Gruppe bei inkrement/dekrement des Werts.");
                     text(ui, "Tooltip bei Buttons/Checkboxes zeigen Änderung der Gewinnchance für diesen Wert an.");
+                    text(ui, "Nicht erfüllte Voraussetzungen von Edges werden unter den passiven Werten angezeigt.");
 
                     header(ui, "Kampf");
-                    text(ui, "Jede Gruppe greift immer den ersten der jeweils anderen Gruppe an.");
+                    text(ui, "Jede Gruppe greift immer den ersten der vorderen Reihe der jeweils anderen Gruppe an.");
+                    text(ui, "Mit Fernkampfwaffe wird geschossen, bis der Gegner im Nahkampf oder die Munition verbraucht ist.");
+                    text(ui, "Mit Heilkunde Wunden oder Heiltränken werden Verbündete unter der Heilschwelle (Taktik) geheilt, statt anzugreifen.");
+                    text(ui, "Natürliche Angriffe ersetzen die Nahkampfwaffen, alle greifen in derselben Aktion ohne Abzüge an.");
+                    text(ui, "Gift und Blutungen kosten zu Beginn jeder Runde LeP, bis sie mit einer Kon-Probe beendet werden.");
+                    text(ui, "Anführer-Edges wirken nur auf Verbündete, der Wert des Anführers zeigt sich in der Gewinnchance der Gruppe.");
+                    text(ui, "Wer den Nahkampf verlässt, erhält von jedem benachbarten, nicht angeschlagenen Gegner einen freien Angriff mit dessen bestem Angriff, ohne Bennies (Taktik: Absetzen). Mit einer Reichweitenwaffe hält man die Gegner auf Abstand und weicht ohne freie Angriffe zurück.");
+                    text(ui, "Leibwachen (Taktik: Beschützen) fangen Angriffe auf Schützlinge ab oder greifen deren Gegner an, die Auswertung zeigt die Überlebenschance der Schützlinge.");
+
+                    header(ui, "Hausregeln");
+                    text(ui, "Über das Zahnrad oben rechts lassen sich Hausregeln einstellen, speichern und laden.");
+                    text(ui, "Die Hausregeln gelten für beide Gruppen und alle Berechnungen.");
+
+                    header(ui, "Szenario");
+                    text(ui, "Über die Karte oben rechts lässt sich das Szenario der Begegnung einstellen, speichern und laden.");
+                    text(ui, "Hinterhalt: Überraschte ziehen in der ersten Runde keine Karte, Angreifer erhalten gegen sie +4 auf Angriff und Schaden.");
+                    text(ui, "Verstärkungen (Taktik: Ankunft in Runde) schließen sich zu Beginn ihrer Runde hinten an ihre Gruppe an.");
+                    text(ui, "Die Umgebung (Licht, Gelände, Wetter) gilt für beide Gruppen gleichermaßen.");
+                    text(ui, "Ziele des Szenarios werden für die linke Gruppe unabhängig vom Sieg ausgewertet und zeigen ihre Erfolgschance in der Auswertung.");
+                    text(ui, "Kämpfer der hinteren Reihe greifen nur mit Reichweitenwaffen an und werden erst angegriffen, wenn die vordere Reihe gefallen ist.");
                 });
             });
         });
@@ -0,0 +1,165 @@
+use crate::app::widgets::{self, BoolStat, DrawInfo, IntStat, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Arcane {
+    pub(crate) power_points: IntStat<0, 50>,
+    pub(crate) bolt: BoolStat,
+    pub(crate) armor: BoolStat,
+    pub(crate) boost: BoolStat,
+    pub(crate) lower: BoolStat,
+    pub(crate) heal: BoolStat,
+    pub(crate) stun: BoolStat,
+}
+
+impl Arcane {
+    pub fn knows(&self, spell: Spell) -> bool {
+        let known = match spell {
+            Spell::Bolt => self.bolt,
+            Spell::Armor => self.armor,
+            Spell::Boost => self.boost,
+            Spell::Lower => self.lower,
+            Spell::Heal => self.heal,
+            Spell::Stun => self.stun,
+        };
+        known.is_set()
+    }
+}
+
+impl Drawable for Arcane {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Magie");
+
+        ui.heading("Magie");
+        grid.show(ui, |ui| {
+            self.power_points.draw(PowerPoints, selection, sim, ui);
+            ui.end_row();
+            self.bolt.draw(Spell::Bolt, selection, sim, ui);
+            ui.end_row();
+            self.armor.draw(Spell::Armor, selection, sim, ui);
+            ui.end_row();
+            self.boost.draw(Spell::Boost, selection, sim, ui);
+            ui.end_row();
+            self.lower.draw(Spell::Lower, selection, sim, ui);
+            ui.end_row();
+            self.heal.draw(Spell::Heal, selection, sim, ui);
+            ui.end_row();
+            self.stun.draw(Spell::Stun, selection, sim, ui);
+            ui.end_row();
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PowerPoints;
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for PowerPoints {
+    fn as_str(&self) -> &'static str {
+        "AsP"
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.arcane.power_points.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.arcane.power_points.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.arcane.power_points.set(value.into()));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spell {
+    Bolt,
+    Armor,
+    Boost,
+    Lower,
+    Heal,
+    Stun,
+}
+
+impl Spell {
+    /// power points needed to cast the spell
+    pub fn cost(self) -> u8 {
+        match self {
+            Self::Bolt => 1,
+            Self::Armor | Self::Boost | Self::Lower | Self::Stun => 2,
+            Self::Heal => 3,
+        }
+    }
+}
+
+impl DrawInfo<BoolStat> for Spell {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bolt => "Geschoss",
+            Self::Armor => "Schutz",
+            Self::Boost => "Kämpfen steigern",
+            Self::Lower => "Kämpfen senken",
+            Self::Heal => "Heilung",
+            Self::Stun => "Betäuben",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::Bolt => "1 AsP: 2W6 Schaden, mit Steigerung 3W6. Auch ohne Kontakt zum Gegner.",
+            Self::Armor => "2 AsP: +2 Rüstung, mit Steigerung +4, für 5 Runden.",
+            Self::Boost => "2 AsP: +1 auf Kämpfen, mit Steigerung +2, für 5 Runden.",
+            Self::Lower => "2 AsP: Gegner -1 auf Kämpfen und PA, mit Steigerung -2, für 5 Runden.",
+            Self::Heal => "3 AsP: heilt 2W6 LeP, mit Steigerung 3W6, sobald unter halben LeP.",
+            Self::Stun => {
+                "2 AsP: Gegner ist angeschlagen, wenn ihm keine Kon-Probe gelingt. Mit \
+                Steigerung Kon-Probe -2 und zusätzlich -2 auf alle Proben bis zum Ende seiner \
+                nächsten Aktion."
+            }
+        };
+        Some(description)
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Bolt => Box::new(|c| c.arcane.bolt.decrement()),
+            Self::Armor => Box::new(|c| c.arcane.armor.decrement()),
+            Self::Boost => Box::new(|c| c.arcane.boost.decrement()),
+            Self::Lower => Box::new(|c| c.arcane.lower.decrement()),
+            Self::Heal => Box::new(|c| c.arcane.heal.decrement()),
+            Self::Stun => Box::new(|c| c.arcane.stun.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Bolt => Box::new(|c| c.arcane.bolt.increment()),
+            Self::Armor => Box::new(|c| c.arcane.armor.increment()),
+            Self::Boost => Box::new(|c| c.arcane.boost.increment()),
+            Self::Lower => Box::new(|c| c.arcane.lower.increment()),
+            Self::Heal => Box::new(|c| c.arcane.heal.increment()),
+            Self::Stun => Box::new(|c| c.arcane.stun.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Bolt => Box::new(move |c| c.arcane.bolt.set(value)),
+            Self::Armor => Box::new(move |c| c.arcane.armor.set(value)),
+            Self::Boost => Box::new(move |c| c.arcane.boost.set(value)),
+            Self::Lower => Box::new(move |c| c.arcane.lower.set(value)),
+            Self::Heal => Box::new(move |c| c.arcane.heal.set(value)),
+            Self::Stun => Box::new(move |c| c.arcane.stun.set(value)),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
@@ -13,6 +13,18 @@ pub struct Attributes {
     pub(crate) wil: Attribute,
 }
 
+impl Attributes {
+    pub fn get(&self, name: AttrName) -> Attribute {
+        match name {
+            AttrName::Ges => self.ges,
+            AttrName::Stä => self.sta,
+            AttrName::Kon => self.kon,
+            AttrName::Int => self.int,
+            AttrName::Wil => self.wil,
+        }
+    }
+}
+
 impl Drawable for Attributes {
     fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
         let grid = widgets::create_grid("Attribute");
@@ -94,6 +106,8 @@ impl DrawInfo<Attribute> for AttrName {
     Copy,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     strum_macros::EnumIter,
     serde::Serialize,
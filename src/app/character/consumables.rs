@@ -0,0 +1,108 @@
+use crate::app::widgets::{self, DrawInfo, IntStat, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Consumables {
+    pub(crate) healing_potions: IntStat<0, 10>,
+    #[serde(default)]
+    pub(crate) strength_elixirs: IntStat<0, 5>,
+    #[serde(default)]
+    pub(crate) throwing_knives: IntStat<0, 10>,
+}
+
+impl Consumables {
+    pub fn count(&self, consumable: Consumable) -> u8 {
+        let count = match consumable {
+            Consumable::HealingPotion => i8::from(self.healing_potions),
+            Consumable::StrengthElixir => i8::from(self.strength_elixirs),
+            Consumable::ThrowingKnife => i8::from(self.throwing_knives),
+        };
+        count.try_into().unwrap_or(0)
+    }
+}
+
+impl Drawable for Consumables {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Verbrauchsgüter");
+
+        ui.heading("Verbrauchsgüter");
+        grid.show(ui, |ui| {
+            self.healing_potions
+                .draw(Consumable::HealingPotion, selection, sim, ui);
+            ui.end_row();
+            self.strength_elixirs
+                .draw(Consumable::StrengthElixir, selection, sim, ui);
+            ui.end_row();
+            self.throwing_knives
+                .draw(Consumable::ThrowingKnife, selection, sim, ui);
+            ui.end_row();
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Consumable {
+    HealingPotion,
+    StrengthElixir,
+    ThrowingKnife,
+}
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for Consumable {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::HealingPotion => "Heiltränke",
+            Self::StrengthElixir => "Stärkeelixiere",
+            Self::ThrowingKnife => "Wurfmesser",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::HealingPotion => "Heilt 2W6 LeP, siehe Heilschwelle unter Taktik.",
+            Self::StrengthElixir => {
+                "+2 Schaden für 5 Runden. Trinken kostet keine Aktion, siehe Taktik."
+            }
+            Self::ThrowingKnife => {
+                "Ges-Probe gegen 4, Schaden Stä+W4. Wird geworfen, solange der Gegner noch \
+                nicht im Nahkampf ist, siehe Taktik."
+            }
+        };
+        Some(description)
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::HealingPotion => Box::new(|c| c.consumables.healing_potions.decrement()),
+            Self::StrengthElixir => Box::new(|c| c.consumables.strength_elixirs.decrement()),
+            Self::ThrowingKnife => Box::new(|c| c.consumables.throwing_knives.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::HealingPotion => Box::new(|c| c.consumables.healing_potions.increment()),
+            Self::StrengthElixir => Box::new(|c| c.consumables.strength_elixirs.increment()),
+            Self::ThrowingKnife => Box::new(|c| c.consumables.throwing_knives.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::HealingPotion => {
+                Box::new(move |c| c.consumables.healing_potions.set(value.into()))
+            }
+            Self::StrengthElixir => {
+                Box::new(move |c| c.consumables.strength_elixirs.set(value.into()))
+            }
+            Self::ThrowingKnife => {
+                Box::new(move |c| c.consumables.throwing_knives.set(value.into()))
+            }
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
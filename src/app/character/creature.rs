@@ -0,0 +1,195 @@
+use crate::app::widgets::{self, BoolStat, DrawInfo, IntStat, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+
+/// Special abilities of monsters and other creatures
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Creature {
+    pub(crate) size: IntStat<-2, 8>,
+    pub(crate) hardy: BoolStat,
+    pub(crate) undead: BoolStat,
+    /// life regained at the start of each round
+    pub(crate) regeneration: IntStat<0, 5>,
+    pub(crate) fear: BoolStat,
+    /// modifier to the Wil roll of the opponents
+    pub(crate) fear_penalty: IntStat<0, 4>,
+    pub(crate) natural_weapons: BoolStat,
+}
+
+impl Creature {
+    pub fn size(&self) -> i8 {
+        self.size.into()
+    }
+
+    /// modifier to attacks against smaller or bigger opponents
+    pub fn scale(&self) -> i8 {
+        match self.size() {
+            ..=-2 => -2,
+            -1..=3 => 0,
+            4..=7 => 2,
+            8.. => 4,
+        }
+    }
+
+    pub fn regeneration(&self) -> u8 {
+        i8::from(self.regeneration).try_into().unwrap_or(0)
+    }
+
+    /// penalty to the Wil roll of the opponents, `None` if the creature isn't scary
+    pub fn fear(&self) -> Option<u8> {
+        if !self.fear.is_set() {
+            return None;
+        }
+        Some(i8::from(self.fear_penalty).try_into().unwrap_or(0))
+    }
+}
+
+impl Drawable for Creature {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Kreatur");
+
+        ui.heading("Kreatur");
+        grid.show(ui, |ui| {
+            self.size.draw(CreatureValue::Size, selection, sim, ui);
+            ui.end_row();
+            self.regeneration
+                .draw(CreatureValue::Regeneration, selection, sim, ui);
+            ui.end_row();
+            self.fear_penalty
+                .draw(CreatureValue::FearPenalty, selection, sim, ui);
+            ui.end_row();
+            self.fear.draw(CreatureAbility::Fear, selection, sim, ui);
+            ui.end_row();
+            self.hardy.draw(CreatureAbility::Hardy, selection, sim, ui);
+            ui.end_row();
+            self.undead
+                .draw(CreatureAbility::Undead, selection, sim, ui);
+            ui.end_row();
+            self.natural_weapons
+                .draw(CreatureAbility::NaturalWeapons, selection, sim, ui);
+            ui.end_row();
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CreatureValue {
+    Size,
+    Regeneration,
+    FearPenalty,
+}
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for CreatureValue {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Size => "Größe",
+            Self::Regeneration => "Regeneration",
+            Self::FearPenalty => "Furcht Abzug",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::Size => {
+                "Addiert sich auf ROB. Ab Größe 4 (groß) +2 für Angreifer, ab 8 (riesig) +4, \
+                bei -2 (klein) -2."
+            }
+            Self::Regeneration => "LeP, die zu Beginn jeder Runde regeneriert werden.",
+            Self::FearPenalty => "Abzug auf die Wil-Probe der Gegner bei Furcht.",
+        };
+        Some(description)
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Size => Box::new(|c| c.creature.size.decrement()),
+            Self::Regeneration => Box::new(|c| c.creature.regeneration.decrement()),
+            Self::FearPenalty => Box::new(|c| c.creature.fear_penalty.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Size => Box::new(|c| c.creature.size.increment()),
+            Self::Regeneration => Box::new(|c| c.creature.regeneration.increment()),
+            Self::FearPenalty => Box::new(|c| c.creature.fear_penalty.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let value = value.into();
+        let modification: simulator::CharModFunc = match self {
+            Self::Size => Box::new(move |c| c.creature.size.set(value)),
+            Self::Regeneration => Box::new(move |c| c.creature.regeneration.set(value)),
+            Self::FearPenalty => Box::new(move |c| c.creature.fear_penalty.set(value)),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CreatureAbility {
+    Fear,
+    Hardy,
+    Undead,
+    NaturalWeapons,
+}
+
+impl DrawInfo<BoolStat> for CreatureAbility {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Fear => "Furcht",
+            Self::Hardy => "Zäh",
+            Self::Undead => "Untot",
+            Self::NaturalWeapons => "Natürliche Waffen",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::Fear => {
+                "Gegner sind angeschlagen, wenn ihnen vor dem Kampf keine Wil-Probe gelingt."
+            }
+            Self::Hardy => "Wird bereits angeschlagen nur durch Treffer mit Steigerung verwundet.",
+            Self::Undead => "Keine Wundabzüge, +2 auf Entschütteln, immun gegen Furcht.",
+            Self::NaturalWeapons => {
+                "Klauen, Zähne usw. können nicht verloren gehen oder zerbrechen."
+            }
+        };
+        Some(description)
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Fear => Box::new(|c| c.creature.fear.decrement()),
+            Self::Hardy => Box::new(|c| c.creature.hardy.decrement()),
+            Self::Undead => Box::new(|c| c.creature.undead.decrement()),
+            Self::NaturalWeapons => Box::new(|c| c.creature.natural_weapons.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Fear => Box::new(|c| c.creature.fear.increment()),
+            Self::Hardy => Box::new(|c| c.creature.hardy.increment()),
+            Self::Undead => Box::new(|c| c.creature.undead.increment()),
+            Self::NaturalWeapons => Box::new(|c| c.creature.natural_weapons.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Fear => Box::new(move |c| c.creature.fear.set(value)),
+            Self::Hardy => Box::new(move |c| c.creature.hardy.set(value)),
+            Self::Undead => Box::new(move |c| c.creature.undead.set(value)),
+            Self::NaturalWeapons => Box::new(move |c| c.creature.natural_weapons.set(value)),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
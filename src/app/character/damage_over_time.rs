@@ -0,0 +1,125 @@
+use crate::app::widgets::{DrawInfo, IntStat, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Character;
+
+/// Poison or bleeding wounds caused by a weapon or natural attack
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct DamageOverTime {
+    /// life lost each round, applied if the hit at least shakes
+    pub(crate) poison: IntStat<0, 6>,
+    /// life lost each round, applied if the hit wounds
+    pub(crate) bleeding: IntStat<0, 3>,
+}
+
+impl DamageOverTime {
+    pub fn poison(&self) -> u8 {
+        i8::from(self.poison).try_into().unwrap_or(0)
+    }
+
+    pub fn bleeding(&self) -> u8 {
+        i8::from(self.bleeding).try_into().unwrap_or(0)
+    }
+
+    /// draw the rows into the grid of the carrier
+    pub(super) fn draw_rows(
+        &mut self,
+        carrier: Carrier,
+        selection: app::CharSelection,
+        sim: &mut Simulator,
+        ui: &mut egui::Ui,
+    ) {
+        self.poison
+            .draw(DotInfo::Poison(carrier), selection, sim, ui);
+        ui.end_row();
+        self.bleeding
+            .draw(DotInfo::Bleeding(carrier), selection, sim, ui);
+        ui.end_row();
+    }
+}
+
+/// What causes the damage over time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Carrier {
+    Primary,
+    Secondary,
+    /// index of the natural attack
+    Natural(usize),
+}
+
+impl Carrier {
+    fn damage_over_time(self, character: &mut Character) -> &mut DamageOverTime {
+        match self {
+            Self::Primary => &mut character.weapon.damage_over_time,
+            Self::Secondary => &mut character.secondary_weapon.damage_over_time,
+            Self::Natural(index) => &mut character.natural_attacks.get_mut(index).damage_over_time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DotInfo {
+    Poison(Carrier),
+    Bleeding(Carrier),
+}
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for DotInfo {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Poison(_) => "Gift (LeP/Runde)",
+            Self::Bleeding(_) => "Blutung (LeP/Runde)",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::Poison(_) => {
+                "Ein Treffer, der mindestens angeschlagen macht, vergiftet. Zu Beginn jeder \
+                Runde verliert das Opfer die LeP und kann das Gift mit einer Kon-Probe beenden."
+            }
+            Self::Bleeding(_) => {
+                "Ein Treffer, der verwundet, lässt bluten. Zu Beginn jeder Runde verliert das \
+                Opfer die LeP und kann die Blutung mit einer Kon-Probe stillen."
+            }
+        };
+        Some(description)
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match *self {
+            Self::Poison(carrier) => {
+                Box::new(move |c| carrier.damage_over_time(c).poison.decrement())
+            }
+            Self::Bleeding(carrier) => {
+                Box::new(move |c| carrier.damage_over_time(c).bleeding.decrement())
+            }
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match *self {
+            Self::Poison(carrier) => {
+                Box::new(move |c| carrier.damage_over_time(c).poison.increment())
+            }
+            Self::Bleeding(carrier) => {
+                Box::new(move |c| carrier.damage_over_time(c).bleeding.increment())
+            }
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let value = value.into();
+        let modification: simulator::CharModFunc = match *self {
+            Self::Poison(carrier) => {
+                Box::new(move |c| carrier.damage_over_time(c).poison.set(value))
+            }
+            Self::Bleeding(carrier) => {
+                Box::new(move |c| carrier.damage_over_time(c).bleeding.set(value))
+            }
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
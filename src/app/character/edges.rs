@@ -1,58 +1,197 @@
+mod definitions;
+mod requirements;
+
+use std::collections::BTreeMap;
+
 use crate::app::widgets::{self, BoolStat, DrawInfo, ValueSelector};
 use crate::simulator::{CharModification, Simulator};
 use crate::{app, simulator};
 
 use super::Drawable;
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub use definitions::{
+    AttackKind, Aura, Capability, DEFINITIONS, EdgeContext, EdgeHooks, EdgeId, EdgeTrigger,
+    Initiative, Modifier, PassiveBonus, Trigger,
+};
+pub use requirements::Violations;
+
+/// The edges of a char and their levels. Edges that are not present are not stored.
+///
+/// A char only has a few edges, so they are kept in a vec sorted by id. This is iterated a lot
+/// during a fight, which is way cheaper than iterating a map.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct Edges {
-    pub(crate) lebenskraft: Edge3,
-    pub(crate) blitzhieb: Edge3,
-    pub(crate) berserker: Edge3,
-    pub(crate) riposte: Edge3,
-    pub(crate) tuchfuhlung: Edge3,
-    pub(crate) kampfreflexe: BoolStat,
-    pub(crate) erstschlag: Edge3,
-    pub(crate) beidhandiger_kampf: BoolStat,
-    pub(crate) beidhandig: BoolStat,
-    pub(crate) fechten_m2w: BoolStat,
-    pub(crate) ubertolpeln: BoolStat,
-    pub(crate) erbarmungslos: BoolStat,
-    pub(crate) machtiger_hieb: BoolStat,
-    pub(crate) schnell: BoolStat,
-    pub(crate) kampfkunstler: BoolStat,
-    pub(crate) kuhler_kopf: Edge3,
-    pub(crate) rundumschlag: BoolStat,
+    levels: Vec<(EdgeId, Edge3)>,
 }
 
 impl Edges {
-    fn edge3_iter(&mut self) -> impl Iterator<Item = (&mut Edge3, Edge3Info)> {
-        [
-            (&mut self.berserker, Edge3Info::Berserker),
-            (&mut self.tuchfuhlung, Edge3Info::Tuchfühlung),
-            (&mut self.lebenskraft, Edge3Info::Lebenskraft),
-            (&mut self.blitzhieb, Edge3Info::Blitzhieb),
-            (&mut self.riposte, Edge3Info::Riposte),
-            (&mut self.kuhler_kopf, Edge3Info::KühlerKopf),
-            (&mut self.erstschlag, Edge3Info::Erstschlag),
-        ]
-        .into_iter()
-    }
-
-    fn edge2_iter(&mut self) -> impl Iterator<Item = (&mut BoolStat, Edge2Info)> {
-        [
-            (&mut self.schnell, Edge2Info::Schnell),
-            (&mut self.rundumschlag, Edge2Info::Rundumschlag),
-            (&mut self.beidhandig, Edge2Info::Beidhändig),
-            (&mut self.beidhandiger_kampf, Edge2Info::BeidhändigerKampf),
-            (&mut self.fechten_m2w, Edge2Info::FechtenMit2Waffen),
-            (&mut self.ubertolpeln, Edge2Info::Übertölpeln),
-            (&mut self.machtiger_hieb, Edge2Info::MächtigerHieb),
-            (&mut self.erbarmungslos, Edge2Info::Erbarmungslos),
-            (&mut self.kampfreflexe, Edge2Info::Kampfreflexe),
-            (&mut self.kampfkunstler, Edge2Info::Kampfkünstler),
-        ]
-        .into_iter()
+    pub fn level(&self, id: EdgeId) -> Edge3 {
+        self.position(id)
+            .map_or(Edge3::None, |index| self.levels[index].1)
+    }
+
+    pub fn has(&self, id: EdgeId) -> bool {
+        self.position(id).is_ok()
+    }
+
+    pub fn set(&mut self, id: EdgeId, level: Edge3) {
+        let level = level.min(id.def().max_level());
+        match (self.position(id), level) {
+            (Ok(index), Edge3::None) => {
+                self.levels.remove(index);
+            }
+            (Ok(index), level) => self.levels[index].1 = level,
+            (Err(_), Edge3::None) => (),
+            (Err(index), level) => self.levels.insert(index, (id, level)),
+        }
+    }
+
+    fn position(&self, id: EdgeId) -> Result<usize, usize> {
+        self.levels.binary_search_by_key(&id, |(id, _level)| *id)
+    }
+
+    pub fn increment(&mut self, id: EdgeId) {
+        let mut level = self.level(id);
+        level.increment();
+        self.set(id, level);
+    }
+
+    pub fn decrement(&mut self, id: EdgeId) {
+        let mut level = self.level(id);
+        level.decrement();
+        self.set(id, level);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EdgeId, Edge3)> {
+        self.levels.iter().copied()
+    }
+
+    pub fn grants(&self, capability: Capability) -> bool {
+        self.iter().any(|(id, _level)| id.def().grants(capability))
+    }
+
+    /// `true` if any edge implements the given hook
+    pub fn has_hook<T>(&self, hook: impl Fn(&EdgeHooks) -> Option<T>) -> bool {
+        self.iter()
+            .any(|(id, _level)| hook(&id.def().hooks).is_some())
+    }
+
+    pub fn passive_bonus(&self) -> PassiveBonus {
+        self.iter()
+            .filter_map(|(id, level)| id.def().hooks.passive.map(|passive| passive(level)))
+            .fold(PassiveBonus::default(), |sum, bonus| PassiveBonus {
+                life: sum.life + bonus.life,
+                parry: sum.parry + bonus.parry,
+                robustness: sum.robustness + bonus.robustness,
+            })
+    }
+
+    /// the aura the char grants its allies
+    pub fn aura(&self) -> Aura {
+        self.iter()
+            .filter_map(|(id, level)| id.def().hooks.aura.map(|aura| aura(level)))
+            .fold(Aura::default(), Aura::max)
+    }
+
+    /// sum up the results of a modifier hook of all edges
+    pub fn modifier(&self, hook: impl Fn(&EdgeHooks) -> Option<Modifier>, ctx: EdgeContext) -> i8 {
+        // this is called very often during a fight, so keep it a simple loop
+        let mut sum = 0;
+        for &(id, level) in &self.levels {
+            if let Some(modifier) = hook(&id.def().hooks) {
+                sum += modifier(&EdgeContext { level, ..ctx });
+            }
+        }
+        sum
+    }
+
+    /// collect the results of a trigger hook of all edges
+    pub fn triggers(
+        &self,
+        hook: impl Fn(&EdgeHooks) -> Option<Trigger>,
+        ctx: EdgeContext,
+    ) -> impl Iterator<Item = (EdgeId, EdgeTrigger)> {
+        self.iter().filter_map(move |(id, level)| {
+            let trigger = hook(&id.def().hooks)?;
+            let result = trigger(&EdgeContext { level, ..ctx })?;
+            Some((id, result))
+        })
+    }
+
+    pub fn attack_dice(&self, ctx: EdgeContext) -> u8 {
+        self.iter()
+            .filter_map(|(id, level)| {
+                let attack_dice = id.def().hooks.attack_dice?;
+                Some(attack_dice(&EdgeContext { level, ..ctx }))
+            })
+            .sum()
+    }
+
+    pub fn gang_up_cap(&self, ctx: EdgeContext) -> Option<u8> {
+        self.iter()
+            .filter_map(|(id, level)| {
+                let gang_up_cap = id.def().hooks.gang_up_cap?;
+                gang_up_cap(&EdgeContext { level, ..ctx })
+            })
+            .min()
+    }
+
+    pub fn initiative(&self, ctx: EdgeContext) -> Initiative {
+        self.iter()
+            .filter_map(|(id, level)| {
+                let initiative = id.def().hooks.initiative?;
+                Some(initiative(&EdgeContext { level, ..ctx }))
+            })
+            .fold(Initiative::default(), |sum, initiative| Initiative {
+                extra_cards: sum.extra_cards + initiative.extra_cards,
+                redraw_below: sum.redraw_below.max(initiative.redraw_below),
+            })
+    }
+}
+
+/// How a single edge is saved: yes/no edges as bool, all others with their level
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum SavedLevel {
+    Flag(bool),
+    Level(Edge3),
+}
+
+impl serde::Serialize for Edges {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(DEFINITIONS.iter().map(|def| {
+            let level = self.level(def.id);
+            let saved = if def.levels.len() == 1 {
+                SavedLevel::Flag(level != Edge3::None)
+            } else {
+                SavedLevel::Level(level)
+            };
+            (def.key, saved)
+        }))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Edges {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved: BTreeMap<String, SavedLevel> = serde::Deserialize::deserialize(deserializer)?;
+        let mut edges = Self::default();
+        for (key, level) in saved {
+            let Some(id) = EdgeId::from_key(&key) else {
+                log::warn!("ignoring unknown edge '{key}'");
+                continue;
+            };
+            let level = match level {
+                SavedLevel::Flag(true) => Edge3::Normal,
+                SavedLevel::Flag(false) => Edge3::None,
+                SavedLevel::Level(level) => level,
+            };
+            edges.set(id, level);
+        }
+        Ok(edges)
     }
 }
 
@@ -62,157 +201,75 @@ impl Drawable for Edges {
 
         ui.heading("Edges");
         grid.show(ui, |ui| {
-            for (edge, info) in self.edge3_iter() {
-                edge.draw(info, selection, sim, ui);
-                ui.end_row();
-            }
-            for (edge, info) in self.edge2_iter() {
-                edge.draw(info, selection, sim, ui);
+            for def in &DEFINITIONS {
+                if def.levels.len() == 1 {
+                    let mut flag = BoolStat::from(self.has(def.id));
+                    flag.draw(def.id, selection, sim, ui);
+                    self.set(def.id, Edge3::from(flag));
+                } else {
+                    let mut level = self.level(def.id);
+                    level.draw(def.id, selection, sim, ui);
+                    self.set(def.id, level);
+                }
                 ui.end_row();
             }
         });
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Edge2Info {
-    Übertölpeln,
-    Erbarmungslos,
-    MächtigerHieb,
-    Kampfreflexe,
-    Schnell,
-    BeidhändigerKampf,
-    Beidhändig,
-    FechtenMit2Waffen,
-    Kampfkünstler,
-    Rundumschlag,
-}
-
-impl DrawInfo<BoolStat> for Edge2Info {
+impl DrawInfo<BoolStat> for EdgeId {
     fn as_str(&self) -> &'static str {
-        match self {
-            Self::Übertölpeln => "Übertölpeln",
-            Self::Erbarmungslos => "Erbarmungslos",
-            Self::MächtigerHieb => "Mächtiger Hieb",
-            Self::BeidhändigerKampf => "Beidhändiger Kampf",
-            Self::Beidhändig => "Beidhändig (Hintergrund)",
-            Self::FechtenMit2Waffen => "Fechten mit zwei Waffen",
-            Self::Kampfreflexe => "Kampfreflexe",
-            Self::Schnell => "Schnell (Hintergrund)",
-            Self::Kampfkünstler => "Kampfkünstler",
-            Self::Rundumschlag => "Rundumschlag",
-        }
+        self.def().name
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(self.def().description)
     }
 
     fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
-        let modification: simulator::CharModFunc = match self {
-            Self::Übertölpeln => Box::new(|c| c.edges.ubertolpeln.decrement()),
-            Self::Erbarmungslos => Box::new(|c| c.edges.erbarmungslos.decrement()),
-            Self::MächtigerHieb => Box::new(|c| c.edges.machtiger_hieb.decrement()),
-            Self::BeidhändigerKampf => Box::new(|c| c.edges.beidhandiger_kampf.decrement()),
-            Self::Beidhändig => Box::new(|c| c.edges.beidhandig.decrement()),
-            Self::FechtenMit2Waffen => Box::new(|c| c.edges.fechten_m2w.decrement()),
-            Self::Kampfreflexe => Box::new(|c| c.edges.kampfreflexe.decrement()),
-            Self::Schnell => Box::new(|c| c.edges.schnell.decrement()),
-            Self::Kampfkünstler => Box::new(|c| c.edges.kampfkunstler.decrement()),
-            Self::Rundumschlag => Box::new(|c| c.edges.rundumschlag.decrement()),
-        };
+        let id = *self;
+        let modification: simulator::CharModFunc = Box::new(move |c| c.edges.decrement(id));
         simulator::CharModification::new(selection, modification)
     }
 
     fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
-        let modification: simulator::CharModFunc = match self {
-            Self::Übertölpeln => Box::new(|c| c.edges.ubertolpeln.increment()),
-            Self::Erbarmungslos => Box::new(|c| c.edges.erbarmungslos.increment()),
-            Self::MächtigerHieb => Box::new(|c| c.edges.machtiger_hieb.increment()),
-            Self::BeidhändigerKampf => Box::new(|c| c.edges.beidhandiger_kampf.increment()),
-            Self::Beidhändig => Box::new(|c| c.edges.beidhandig.increment()),
-            Self::FechtenMit2Waffen => Box::new(|c| c.edges.fechten_m2w.increment()),
-            Self::Kampfreflexe => Box::new(|c| c.edges.kampfreflexe.increment()),
-            Self::Schnell => Box::new(|c| c.edges.schnell.increment()),
-            Self::Kampfkünstler => Box::new(|c| c.edges.kampfkunstler.increment()),
-            Self::Rundumschlag => Box::new(|c| c.edges.rundumschlag.increment()),
-        };
+        let id = *self;
+        let modification: simulator::CharModFunc = Box::new(move |c| c.edges.increment(id));
         simulator::CharModification::new(selection, modification)
     }
 
     fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
-        let modification: simulator::CharModFunc = match self {
-            Self::Übertölpeln => Box::new(move |c| c.edges.ubertolpeln.set(value)),
-            Self::Erbarmungslos => Box::new(move |c| c.edges.erbarmungslos.set(value)),
-            Self::MächtigerHieb => Box::new(move |c| c.edges.machtiger_hieb.set(value)),
-            Self::BeidhändigerKampf => Box::new(move |c| c.edges.beidhandiger_kampf.set(value)),
-            Self::Beidhändig => Box::new(move |c| c.edges.beidhandig.set(value)),
-            Self::FechtenMit2Waffen => Box::new(move |c| c.edges.fechten_m2w.set(value)),
-            Self::Kampfreflexe => Box::new(move |c| c.edges.kampfreflexe.set(value)),
-            Self::Schnell => Box::new(move |c| c.edges.schnell.set(value)),
-            Self::Kampfkünstler => Box::new(move |c| c.edges.kampfkunstler.set(value)),
-            Self::Rundumschlag => Box::new(move |c| c.edges.rundumschlag.set(value)),
-        };
+        let id = *self;
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.edges.set(id, Edge3::from(value)));
         simulator::CharModification::new(selection, modification)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Edge3Info {
-    Blitzhieb,
-    Erstschlag,
-    Berserker,
-    Riposte,
-    Tuchfühlung,
-    Lebenskraft,
-    KühlerKopf,
-}
-
-impl DrawInfo<Edge3> for Edge3Info {
+impl DrawInfo<Edge3> for EdgeId {
     fn as_str(&self) -> &'static str {
-        match self {
-            Self::Blitzhieb => "Blitzhieb",
-            Self::Erstschlag => "Erstschlag",
-            Self::Berserker => "Berserker (Hintergrund)",
-            Self::Riposte => "Riposte",
-            Self::Tuchfühlung => "Tuchfühlung",
-            Self::Lebenskraft => "Lebenskraft",
-            Self::KühlerKopf => "Kühler Kopf",
-        }
+        self.def().name
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(self.def().description)
     }
 
     fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
-        let modification: simulator::CharModFunc = match self {
-            Self::Blitzhieb => Box::new(|c| c.edges.blitzhieb.decrement()),
-            Self::Erstschlag => Box::new(|c| c.edges.erstschlag.decrement()),
-            Self::Berserker => Box::new(|c| c.edges.berserker.decrement()),
-            Self::Riposte => Box::new(|c| c.edges.riposte.decrement()),
-            Self::Tuchfühlung => Box::new(|c| c.edges.tuchfuhlung.decrement()),
-            Self::Lebenskraft => Box::new(|c| c.edges.lebenskraft.decrement()),
-            Self::KühlerKopf => Box::new(|c| c.edges.kuhler_kopf.decrement()),
-        };
+        let id = *self;
+        let modification: simulator::CharModFunc = Box::new(move |c| c.edges.decrement(id));
         simulator::CharModification::new(selection, modification)
     }
 
     fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
-        let modification: simulator::CharModFunc = match self {
-            Self::Blitzhieb => Box::new(|c| c.edges.blitzhieb.increment()),
-            Self::Erstschlag => Box::new(|c| c.edges.erstschlag.increment()),
-            Self::Berserker => Box::new(|c| c.edges.berserker.increment()),
-            Self::Riposte => Box::new(|c| c.edges.riposte.increment()),
-            Self::Tuchfühlung => Box::new(|c| c.edges.tuchfuhlung.increment()),
-            Self::Lebenskraft => Box::new(|c| c.edges.lebenskraft.increment()),
-            Self::KühlerKopf => Box::new(|c| c.edges.kuhler_kopf.increment()),
-        };
+        let id = *self;
+        let modification: simulator::CharModFunc = Box::new(move |c| c.edges.increment(id));
         simulator::CharModification::new(selection, modification)
     }
 
     fn mod_set(&self, selection: app::CharSelection, value: Edge3) -> CharModification {
-        let modification: simulator::CharModFunc = match self {
-            Self::Blitzhieb => Box::new(move |c| c.edges.blitzhieb = value),
-            Self::Erstschlag => Box::new(move |c| c.edges.erstschlag = value),
-            Self::Berserker => Box::new(move |c| c.edges.berserker = value),
-            Self::Riposte => Box::new(move |c| c.edges.riposte = value),
-            Self::Tuchfühlung => Box::new(move |c| c.edges.tuchfuhlung = value),
-            Self::Lebenskraft => Box::new(move |c| c.edges.lebenskraft = value),
-            Self::KühlerKopf => Box::new(move |c| c.edges.kuhler_kopf = value),
-        };
+        let id = *self;
+        let modification: simulator::CharModFunc = Box::new(move |c| c.edges.set(id, value));
         simulator::CharModification::new(selection, modification)
     }
 }
@@ -224,6 +281,8 @@ impl DrawInfo<Edge3> for Edge3Info {
     Copy,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     strum_macros::EnumIter,
     serde::Serialize,
@@ -236,8 +295,18 @@ pub enum Edge3 {
     Improved,
 }
 
+impl From<BoolStat> for Edge3 {
+    fn from(value: BoolStat) -> Self {
+        if value.is_set() {
+            Self::Normal
+        } else {
+            Self::None
+        }
+    }
+}
+
 impl ValueSelector for Edge3 {
-    type Info = Edge3Info;
+    type Info = EdgeId;
 
     fn possible_values() -> impl Iterator<Item = Self> {
         use strum::IntoEnumIterator as _;
@@ -246,22 +315,11 @@ impl ValueSelector for Edge3 {
     }
 
     fn as_str(&self, info: &Self::Info) -> &'static str {
-        match (self, info) {
-            (Self::None, _) => "Nein",
-            (Self::Normal, Edge3Info::Blitzhieb) => "Blitzhieb",
-            (Self::Improved, Edge3Info::Blitzhieb) => "Verb. Blitzhieb",
-            (Self::Normal, Edge3Info::Erstschlag) => "Erstschlag",
-            (Self::Improved, Edge3Info::Erstschlag) => "Verb. Erstschlag",
-            (Self::Normal, Edge3Info::Berserker) => "Berserker",
-            (Self::Improved, Edge3Info::Berserker) => "Berserker Sofort",
-            (Self::Normal, Edge3Info::Riposte) => "Riposte",
-            (Self::Improved, Edge3Info::Riposte) => "Verb. Riposte",
-            (Self::Normal, Edge3Info::Tuchfühlung) => "Tuchfühlung",
-            (Self::Improved, Edge3Info::Tuchfühlung) => "Meisterl. Tuchfühlung",
-            (Self::Normal, Edge3Info::Lebenskraft) => "Lebenskraft",
-            (Self::Improved, Edge3Info::Lebenskraft) => "Noch mehr Lebenskraft",
-            (Self::Normal, Edge3Info::KühlerKopf) => "Kühler Kopf",
-            (Self::Improved, Edge3Info::KühlerKopf) => "Kühlerer Kopf",
+        let levels = info.def().levels;
+        match self {
+            Self::None => "Nein",
+            Self::Normal => levels[0],
+            Self::Improved => levels.get(1).unwrap_or(&levels[0]),
         }
     }
 }
@@ -285,3 +343,24 @@ impl Edge3 {
         *self = new;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edges_keep_saved_format() {
+        let mut edges = Edges::default();
+        edges.set(EdgeId::Blitzhieb, Edge3::Improved);
+        edges.set(EdgeId::Kampfreflexe, Edge3::Normal);
+
+        let json = serde_json::to_value(&edges).unwrap();
+        assert_eq!(json["blitzhieb"], "Improved");
+        assert_eq!(json["kampfreflexe"], true);
+        assert_eq!(json["riposte"], "None");
+        assert_eq!(json["schnell"], false);
+
+        let loaded: Edges = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded, edges);
+    }
+}
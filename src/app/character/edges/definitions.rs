@@ -0,0 +1,662 @@
+//! Definitions of all edges and their effects.
+//!
+//! Every edge is described by an [`EdgeDef`] in [`DEFINITIONS`]. The editor and the simulator
+//! only work with these definitions, so adding an edge means adding an [`EdgeId`] variant and a
+//! table entry here.
+
+use crate::app::character::attributes::{AttrName, Attribute};
+use crate::app::character::rank::Rank;
+use crate::app::character::skills::{Skill, SkillName};
+
+use super::Edge3;
+use super::requirements::Requirement;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum_macros::EnumIter)]
+pub enum EdgeId {
+    Berserker,
+    Tuchfühlung,
+    Lebenskraft,
+    Blitzhieb,
+    Riposte,
+    KühlerKopf,
+    Erstschlag,
+    Schnell,
+    Rundumschlag,
+    Beidhändig,
+    BeidhändigerKampf,
+    FechtenMit2Waffen,
+    Übertölpeln,
+    MächtigerHieb,
+    Erbarmungslos,
+    Kampfreflexe,
+    Kampfkünstler,
+    Kommandieren,
+    HaltDieLinie,
+    Inspirieren,
+}
+
+impl EdgeId {
+    pub fn def(self) -> &'static EdgeDef {
+        let def = &DEFINITIONS[self as usize];
+        debug_assert_eq!(def.id, self, "definitions are not in the order of EdgeId");
+        def
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        DEFINITIONS
+            .iter()
+            .find(|def| def.key == key)
+            .map(|def| def.id)
+    }
+}
+
+/// Static description of an edge
+#[derive(Debug)]
+pub struct EdgeDef {
+    pub id: EdgeId,
+    /// key used when saving a char
+    pub key: &'static str,
+    pub name: &'static str,
+    /// names of the levels, one entry per level (a single entry means yes/no)
+    pub levels: &'static [&'static str],
+    pub description: &'static str,
+    pub grants: &'static [Capability],
+    /// prerequisites of the normal and the improved level, the latter also needs the former
+    pub requires: [&'static [Requirement]; 2],
+    pub hooks: EdgeHooks,
+}
+
+impl EdgeDef {
+    pub fn max_level(&self) -> Edge3 {
+        match self.levels.len() {
+            1 => Edge3::Normal,
+            _ => Edge3::Improved,
+        }
+    }
+
+    pub fn requires(&self, level: Edge3) -> &'static [Requirement] {
+        match level {
+            Edge3::None => &[],
+            Edge3::Normal => self.requires[0],
+            Edge3::Improved => self.requires[1],
+        }
+    }
+
+    pub fn grants(&self, capability: Capability) -> bool {
+        self.grants.contains(&capability)
+    }
+}
+
+/// Behaviour of the fighter that is enabled by an edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// attack all opponents in base contact with one roll
+    SweepAttack,
+    /// wild attacks are also possible for counter attacks
+    WildCounterAttacks,
+    /// damage bonus of a joker is doubled
+    DoubleJokerDamage,
+    /// never unarmed, can't lose the weapon and counts as armed with two weapons
+    MartialArtist,
+}
+
+/// The situation an edge hook is evaluated in
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EdgeContext {
+    pub level: Edge3,
+    pub attack: Option<AttackKind>,
+    /// owner fights with a weapon in each hand
+    pub dual_wielding: bool,
+    pub opponent_reach: u8,
+    pub opponent_shaken: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackKind {
+    Primary,
+    Secondary,
+    Sweep,
+    Counter,
+    /// against an opponent withdrawing from melee
+    Free,
+}
+
+pub type Modifier = fn(&EdgeContext) -> i8;
+pub type Trigger = fn(&EdgeContext) -> Option<EdgeTrigger>;
+
+/// Effects of an edge. Every hook is optional, the simulator sums up the results of all edges.
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeHooks {
+    /// static bonus that is also shown in the editor
+    pub passive: Option<fn(Edge3) -> PassiveBonus>,
+    pub attack: Option<Modifier>,
+    /// additional attack dice
+    pub attack_dice: Option<fn(&EdgeContext) -> u8>,
+    pub parry: Option<Modifier>,
+    pub damage: Option<Modifier>,
+    /// modifier to the damage received, e.g. for additional robustness
+    pub damage_received: Option<Modifier>,
+    pub unshake: Option<Modifier>,
+    /// cap of the gang-up bonus opponents get against the owner
+    pub gang_up_cap: Option<fn(&EdgeContext) -> Option<u8>>,
+    pub initiative: Option<fn(&EdgeContext) -> Initiative>,
+    /// called when the fight starts
+    pub on_fight_start: Option<Trigger>,
+    /// called when the owner was hit and received damage
+    pub on_hit: Option<Trigger>,
+    /// called when an opponent missed the owner
+    pub on_miss: Option<Trigger>,
+    /// called when an opponent steps into base contact with the owner
+    pub on_approach: Option<Trigger>,
+    /// bonus for all allies of the owner, the owner doesn't profit itself
+    pub aura: Option<fn(Edge3) -> Aura>,
+}
+
+impl EdgeHooks {
+    const NONE: Self = Self {
+        passive: None,
+        attack: None,
+        attack_dice: None,
+        parry: None,
+        damage: None,
+        damage_received: None,
+        unshake: None,
+        gang_up_cap: None,
+        initiative: None,
+        on_fight_start: None,
+        on_hit: None,
+        on_miss: None,
+        on_approach: None,
+        aura: None,
+    };
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PassiveBonus {
+    pub life: i8,
+    pub parry: i8,
+    pub robustness: i8,
+}
+
+/// Bonus of a leader for its group, auras of several leaders don't stack
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Aura {
+    pub unshake: i8,
+    pub robustness: i8,
+    /// once per round, the leader may unshake all allies with a Wil roll
+    pub inspire: bool,
+}
+
+impl Aura {
+    /// the best bonus of both auras
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            unshake: self.unshake.max(other.unshake),
+            robustness: self.robustness.max(other.robustness),
+            inspire: self.inspire || other.inspire,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Initiative {
+    /// number of additional cards to draw, the best one is taken
+    pub extra_cards: u8,
+    /// cards with a lower value are redrawn
+    pub redraw_below: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeTrigger {
+    /// start raging like a berserker
+    Rage,
+    /// immediately do a counter attack against the opponent
+    Counter { once_per_round: bool },
+}
+
+pub static DEFINITIONS: [EdgeDef; 20] = [
+    EdgeDef {
+        id: EdgeId::Berserker,
+        key: "berserker",
+        name: "Berserker (Hintergrund)",
+        levels: &["Berserker", "Berserker Sofort"],
+        description: "Nach Schaden in Raserei: +2 AT, Schaden und ROB, -2 PA, keine Wundabzüge, \
+            kämpft bis 0 LeP, Patzer bei jeder 1. Sofort: Raserei ab Kampfbeginn.",
+        grants: &[],
+        requires: [
+            &[Requirement::Rank(Rank::Novice)],
+            &[Requirement::Rank(Rank::Seasoned)],
+        ],
+        // the bonuses of the rage itself are timed modifiers of the fighter
+        hooks: EdgeHooks {
+            on_fight_start: Some(|ctx| (ctx.level == Edge3::Improved).then_some(EdgeTrigger::Rage)),
+            on_hit: Some(|_ctx| Some(EdgeTrigger::Rage)),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Tuchfühlung,
+        key: "tuchfuhlung",
+        name: "Tuchfühlung",
+        levels: &["Tuchfühlung", "Meisterl. Tuchfühlung"],
+        description: "PA +1 plus Reichweite des Gegners. Meisterlich: auch AT +1 plus Reichweite.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Ges, Attribute::W8),
+                Requirement::Skill(SkillName::Kämpfen, Skill::W8),
+            ],
+            &[Requirement::Rank(Rank::Seasoned)],
+        ],
+        hooks: EdgeHooks {
+            attack: Some(|ctx| match ctx.level {
+                Edge3::Improved => 1 + ctx.opponent_reach.cast_signed(),
+                _ => 0,
+            }),
+            parry: Some(|ctx| 1 + ctx.opponent_reach.cast_signed()),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Lebenskraft,
+        key: "lebenskraft",
+        name: "Lebenskraft",
+        levels: &["Lebenskraft", "Noch mehr Lebenskraft"],
+        description: "+5 LeP, verbessert +10 LeP.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Kon, Attribute::W6),
+            ],
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Attribute(AttrName::Kon, Attribute::W8),
+            ],
+        ],
+        hooks: EdgeHooks {
+            passive: Some(|level| PassiveBonus {
+                life: if level == Edge3::Improved { 10 } else { 5 },
+                ..PassiveBonus::default()
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Blitzhieb,
+        key: "blitzhieb",
+        name: "Blitzhieb",
+        levels: &["Blitzhieb", "Verb. Blitzhieb"],
+        description: "Zwei Angriffe mit der Hauptwaffe, je -2 AT. Verbessert ohne Abzug.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Skill(SkillName::Kämpfen, Skill::W10),
+            ],
+            &[Requirement::Rank(Rank::Veteran)],
+        ],
+        hooks: EdgeHooks {
+            attack: Some(|ctx| match (ctx.attack, ctx.level) {
+                (Some(AttackKind::Primary), Edge3::Normal) => -2,
+                _ => 0,
+            }),
+            attack_dice: Some(|ctx| u8::from(ctx.attack == Some(AttackKind::Primary))),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Riposte,
+        key: "riposte",
+        name: "Riposte",
+        levels: &["Riposte", "Verb. Riposte"],
+        description: "Freier Angriff, wenn ein Gegner verfehlt. Einmal pro Runde, verbessert \
+            immer.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Skill(SkillName::Kämpfen, Skill::W8),
+            ],
+            &[Requirement::Rank(Rank::Veteran)],
+        ],
+        hooks: EdgeHooks {
+            on_miss: Some(|ctx| {
+                let once_per_round = ctx.level != Edge3::Improved;
+                Some(EdgeTrigger::Counter { once_per_round })
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::KühlerKopf,
+        key: "kuhler_kopf",
+        name: "Kühler Kopf",
+        levels: &["Kühler Kopf", "Kühlerer Kopf"],
+        description: "Zieht eine, verbessert zwei zusätzliche Initiativkarten.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Attribute(AttrName::Int, Attribute::W8),
+            ],
+            &[Requirement::Rank(Rank::Seasoned)],
+        ],
+        hooks: EdgeHooks {
+            initiative: Some(|ctx| Initiative {
+                extra_cards: if ctx.level == Edge3::Improved { 2 } else { 1 },
+                ..Initiative::default()
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Erstschlag,
+        key: "erstschlag",
+        name: "Erstschlag",
+        levels: &["Erstschlag", "Verb. Erstschlag"],
+        description: "Freier Angriff, wenn ein Gegner in Kontakt tritt. Einmal pro Runde, \
+            verbessert immer. Tritt nach dem Angriff zurück, wenn kein benachbarter Gegner \
+            angreifen kann, mit der Taktik Absetzen auch gegen freie Angriffe.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Ges, Attribute::W8),
+            ],
+            &[Requirement::Rank(Rank::Heroic)],
+        ],
+        hooks: EdgeHooks {
+            on_approach: Some(|ctx| {
+                let once_per_round = ctx.level != Edge3::Improved;
+                Some(EdgeTrigger::Counter { once_per_round })
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Schnell,
+        key: "schnell",
+        name: "Schnell (Hintergrund)",
+        levels: &["Schnell"],
+        description: "Initiativkarten unter 7 werden neu gezogen.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Ges, Attribute::W8),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            initiative: Some(|_ctx| Initiative {
+                redraw_below: 7,
+                ..Initiative::default()
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Rundumschlag,
+        key: "rundumschlag",
+        name: "Rundumschlag",
+        levels: &["Rundumschlag"],
+        description: "Greift mit einem Wurf alle Gegner im Kontakt an, sobald es mindestens zwei \
+            sind.",
+        grants: &[Capability::SweepAttack],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Stä, Attribute::W8),
+                Requirement::Skill(SkillName::Kämpfen, Skill::W8),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks::NONE,
+    },
+    EdgeDef {
+        id: EdgeId::Beidhändig,
+        key: "beidhandig",
+        name: "Beidhändig (Hintergrund)",
+        levels: &["Beidhändig"],
+        description: "Kein Abzug für die Zweithand, PA +1.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Ges, Attribute::W8),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            passive: Some(|_level| PassiveBonus {
+                // it is hard to represent "using" another weapon without necessarily attacking
+                // with it, so just assume there is one if the edge is present
+                parry: 1,
+                ..PassiveBonus::default()
+            }),
+            attack: Some(|ctx| {
+                if ctx.attack == Some(AttackKind::Secondary) {
+                    2
+                } else {
+                    0
+                }
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::BeidhändigerKampf,
+        key: "beidhandiger_kampf",
+        name: "Beidhändiger Kampf",
+        levels: &["Beidhändiger Kampf"],
+        description: "Kein Abzug für den Kampf mit zwei Waffen.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Ges, Attribute::W8),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            attack: Some(|ctx| {
+                let regular_attack = matches!(
+                    ctx.attack,
+                    Some(AttackKind::Primary | AttackKind::Secondary | AttackKind::Sweep)
+                );
+                if regular_attack && ctx.dual_wielding {
+                    2
+                } else {
+                    0
+                }
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::FechtenMit2Waffen,
+        key: "fechten_m2w",
+        name: "Fechten mit zwei Waffen",
+        levels: &["Fechten mit zwei Waffen"],
+        description: "Gegner erhalten keinen Überzahlbonus, wenn mit zwei Waffen gekämpft wird.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Edge(EdgeId::BeidhändigerKampf, Edge3::Normal),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            gang_up_cap: Some(|ctx| ctx.dual_wielding.then_some(0)),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Übertölpeln,
+        key: "ubertolpeln",
+        name: "Übertölpeln",
+        levels: &["Übertölpeln"],
+        description: "+4 Schaden gegen angeschlagene Gegner.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Attribute(AttrName::Int, Attribute::W6),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            damage: Some(|ctx| if ctx.opponent_shaken { 4 } else { 0 }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::MächtigerHieb,
+        key: "machtiger_hieb",
+        name: "Mächtiger Hieb",
+        levels: &["Mächtiger Hieb"],
+        description: "Doppelter Schaden mit Joker.",
+        grants: &[Capability::DoubleJokerDamage],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Skill(SkillName::Kämpfen, Skill::W8),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks::NONE,
+    },
+    EdgeDef {
+        id: EdgeId::Erbarmungslos,
+        key: "erbarmungslos",
+        name: "Erbarmungslos",
+        levels: &["Erbarmungslos"],
+        description: "Wilde Angriffe auch bei Riposte und Erstschlag.",
+        grants: &[Capability::WildCounterAttacks],
+        requires: [&[Requirement::Rank(Rank::Seasoned)], &[]],
+        hooks: EdgeHooks::NONE,
+    },
+    EdgeDef {
+        id: EdgeId::Kampfreflexe,
+        key: "kampfreflexe",
+        name: "Kampfreflexe",
+        levels: &["Kampfreflexe"],
+        description: "+2 zum Entschütteln.",
+        grants: &[],
+        requires: [&[Requirement::Rank(Rank::Seasoned)], &[]],
+        hooks: EdgeHooks {
+            unshake: Some(|_ctx| 2),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Kampfkünstler,
+        key: "kampfkunstler",
+        name: "Kampfkünstler",
+        levels: &["Kampfkünstler"],
+        description: "Gilt nie als unbewaffnet, verliert keine Waffe und zählt als mit zwei \
+            Waffen bewaffnet.",
+        grants: &[Capability::MartialArtist],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Skill(SkillName::Kämpfen, Skill::W6),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks::NONE,
+    },
+    EdgeDef {
+        id: EdgeId::Kommandieren,
+        key: "kommandieren",
+        name: "Kommandieren (Anführer)",
+        levels: &["Kommandieren"],
+        description: "Verbündete erhalten +1 zum Entschütteln.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Novice),
+                Requirement::Attribute(AttrName::Int, Attribute::W6),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            aura: Some(|_level| Aura {
+                unshake: 1,
+                ..Aura::default()
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::HaltDieLinie,
+        key: "halt_die_linie",
+        name: "Halt die Linie! (Anführer)",
+        levels: &["Halt die Linie!"],
+        description: "Verbündete erhalten ROB +1.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Attribute(AttrName::Int, Attribute::W8),
+                Requirement::Edge(EdgeId::Kommandieren, Edge3::Normal),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            aura: Some(|_level| Aura {
+                robustness: 1,
+                ..Aura::default()
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+    EdgeDef {
+        id: EdgeId::Inspirieren,
+        key: "inspirieren",
+        name: "Inspirieren (Anführer)",
+        levels: &["Inspirieren"],
+        description: "Einmal pro Runde in der eigenen Runde: eine Wil-Probe als freie Aktion \
+            entschüttelt alle Verbündeten.",
+        grants: &[],
+        requires: [
+            &[
+                Requirement::Rank(Rank::Seasoned),
+                Requirement::Edge(EdgeId::Kommandieren, Edge3::Normal),
+            ],
+            &[],
+        ],
+        hooks: EdgeHooks {
+            aura: Some(|_level| Aura {
+                inspire: true,
+                ..Aura::default()
+            }),
+            ..EdgeHooks::NONE
+        },
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator as _;
+
+    use super::*;
+
+    #[test]
+    fn test_definitions_are_in_order() {
+        for id in EdgeId::iter() {
+            assert_eq!(DEFINITIONS[id as usize].id, id);
+        }
+        assert_eq!(EdgeId::iter().count(), DEFINITIONS.len());
+    }
+
+    #[test]
+    fn test_keys_are_unique() {
+        for id in EdgeId::iter() {
+            assert_eq!(EdgeId::from_key(id.def().key), Some(id));
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Prerequisites of edges and the validation of a char against them.
+
+use std::fmt;
+
+use crate::app::character::attributes::{AttrName, Attribute};
+use crate::app::character::rank::Rank;
+use crate::app::character::skills::{Skill, SkillName};
+use crate::app::character::{Character, Drawable, EdgeId};
+use crate::app::widgets::{DrawInfo as _, ValueSelector as _};
+use crate::{app, simulator::Simulator};
+
+use super::Edge3;
+
+/// A single prerequisite of an edge level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    Rank(Rank),
+    Attribute(AttrName, Attribute),
+    Skill(SkillName, Skill),
+    Edge(EdgeId, Edge3),
+}
+
+impl Requirement {
+    pub fn is_met(self, character: &Character) -> bool {
+        match self {
+            Self::Rank(rank) => character.experience.rank() >= rank,
+            Self::Attribute(name, min) => character.attributes.get(name) >= min,
+            Self::Skill(name, min) => character.skills.get(name) >= min,
+            Self::Edge(id, min) => character.edges.level(id) >= min,
+        }
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rank(rank) => write!(f, "Rang {}", rank.as_str()),
+            Self::Attribute(name, min) => write!(f, "{} {}", name.as_str(), min.as_str(name)),
+            Self::Skill(name, min) => write!(f, "{} {}", name.as_str(), min.as_str(name)),
+            Self::Edge(id, min) => write!(f, "{}", min.as_str(id)),
+        }
+    }
+}
+
+/// An edge level a char has without meeting one of its prerequisites
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub edge: EdgeId,
+    pub level: Edge3,
+    pub requirement: Requirement,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = self.level.as_str(&self.edge);
+        write!(f, "{level} benötigt {}", self.requirement)
+    }
+}
+
+/// All violations of a char, drawn as a warning in the editor
+#[derive(Debug, Clone)]
+pub struct Violations(Vec<Violation>);
+
+impl Violations {
+    pub fn new(character: &Character) -> Self {
+        Self(validate(character))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// one warning per violation, also shown when a char is loaded
+    pub fn draw_warnings(&self, ui: &mut egui::Ui) {
+        for violation in &self.0 {
+            ui.colored_label(ui.visuals().warn_fg_color, violation.to_string());
+        }
+    }
+}
+
+impl Drawable for Violations {
+    fn draw(&mut self, _selection: app::CharSelection, _sim: &mut Simulator, ui: &mut egui::Ui) {
+        ui.heading("Voraussetzungen");
+        self.draw_warnings(ui);
+    }
+}
+
+/// check all edges of a char against their prerequisites
+pub fn validate(character: &Character) -> Vec<Violation> {
+    character
+        .edges
+        .iter()
+        .flat_map(|(edge, current)| {
+            let def = edge.def();
+            [Edge3::Normal, Edge3::Improved]
+                .into_iter()
+                .filter(move |level| *level <= current)
+                .flat_map(move |level| {
+                    def.requires(level)
+                        .iter()
+                        .map(move |requirement| Violation {
+                            edge,
+                            level,
+                            requirement: *requirement,
+                        })
+                })
+        })
+        .filter(|violation| !violation.requirement.is_met(character))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_improved_level_needs_all_requirements() {
+        let mut character = Character::default();
+        character.edges.set(EdgeId::Blitzhieb, Edge3::Improved);
+        let violations = validate(&character);
+        assert!(violations.iter().all(|v| v.edge == EdgeId::Blitzhieb));
+        assert!(violations.iter().any(|v| v.level == Edge3::Normal));
+        assert!(violations.iter().any(|v| v.level == Edge3::Improved));
+
+        character.experience.advances.set(8);
+        character.skills.kampfen = Skill::W10;
+        assert!(validate(&character).is_empty());
+    }
+}
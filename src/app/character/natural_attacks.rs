@@ -0,0 +1,95 @@
+use crate::app::widgets::{self, DrawInfo, IntStat, ValueSelector as _, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+use super::damage_over_time::{Carrier, DamageOverTime};
+use super::weapon::{Damage, DamageName};
+
+/// Claws, bites etc. of a creature, all of them attack in the same action
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct NaturalAttacks {
+    attacks: [NaturalAttack; NaturalAttacks::COUNT],
+}
+
+impl NaturalAttacks {
+    pub const COUNT: usize = 3;
+
+    pub fn get(&self, index: usize) -> &NaturalAttack {
+        &self.attacks[index]
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> &mut NaturalAttack {
+        &mut self.attacks[index]
+    }
+
+    pub fn any_active(&self) -> bool {
+        self.attacks.iter().any(NaturalAttack::active)
+    }
+}
+
+impl Drawable for NaturalAttacks {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Natürliche Angriffe");
+
+        ui.heading("Natürliche Angriffe");
+        grid.show(ui, |ui| {
+            for (index, attack) in self.attacks.iter_mut().enumerate() {
+                attack
+                    .damage
+                    .draw(DamageName::Natural(index), selection, sim, ui);
+                ui.end_row();
+                attack.piercing.draw(Piercing(index), selection, sim, ui);
+                ui.end_row();
+                attack
+                    .damage_over_time
+                    .draw_rows(Carrier::Natural(index), selection, sim, ui);
+            }
+        });
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct NaturalAttack {
+    pub(crate) damage: Damage,
+    pub(crate) piercing: IntStat<0, 3>,
+    #[serde(default)]
+    pub(crate) damage_over_time: DamageOverTime,
+}
+
+impl NaturalAttack {
+    pub fn active(&self) -> bool {
+        self.damage != Damage::None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Piercing(usize);
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for Piercing {
+    fn as_str(&self) -> &'static str {
+        "Panzerbrechend"
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let index = self.0;
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.natural_attacks.get_mut(index).piercing.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let index = self.0;
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.natural_attacks.get_mut(index).piercing.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let index = self.0;
+        let value = value.into();
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.natural_attacks.get_mut(index).piercing.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
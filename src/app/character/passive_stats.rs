@@ -1,4 +1,5 @@
 use super::{Character, Drawable};
+use crate::app::rules::RuleSet;
 use crate::app::widgets::{self, BoolStat, DrawInfo, IntStat, ValueSlider as _};
 use crate::simulator::{CharModification, Simulator};
 use crate::{app, simulator};
@@ -151,41 +152,41 @@ pub struct PassiveStats {
 }
 
 impl PassiveStats {
-    pub fn new(character: &Character) -> Self {
+    pub fn new(character: &Character, rules: &RuleSet) -> Self {
         Self {
-            life: Self::calc_life(character),
-            parry: Self::calc_parry(character),
-            robustness: Self::calc_robustness(character),
+            life: Self::calc_life(character, rules),
+            parry: Self::calc_parry(character, rules),
+            robustness: Self::calc_robustness(character, rules),
         }
     }
 
-    fn calc_life(character: &Character) -> u8 {
-        let mut life = 24 + u8::from(character.attributes.kon) + u8::from(character.attributes.wil);
-        let edge_bonus = match character.edges.lebenskraft {
-            super::Edge3::None => 0,
-            super::Edge3::Normal => 5,
-            super::Edge3::Improved => 10,
-        };
-        life = life.saturating_add(edge_bonus);
+    fn calc_life(character: &Character, rules: &RuleSet) -> u8 {
+        let mut life = rules
+            .base_life
+            .saturating_add(u8::from(character.attributes.kon))
+            .saturating_add(u8::from(character.attributes.wil));
+        life = life.saturating_add_signed(character.edges.passive_bonus().life);
         life = life.saturating_add_signed(character.passive_modifiers.life.into());
         life
     }
 
-    fn calc_parry(character: &Character) -> u8 {
-        let mut parry = 2 + u8::from(character.skills.kampfen) / 2;
+    fn calc_parry(character: &Character, rules: &RuleSet) -> u8 {
+        let mut parry = rules
+            .base_parry
+            .saturating_add(u8::from(character.skills.kampfen) / 2);
         parry = parry.saturating_add_signed(character.passive_modifiers.parry.into());
-        if character.edges.beidhandig.is_set() {
-            // it is hard to represent "using" another weapon without necessarily attacking
-            // with it, so just assume there is one if the edge is present
-            parry += 1;
-        }
+        parry = parry.saturating_add_signed(character.edges.passive_bonus().parry);
         parry
     }
 
-    fn calc_robustness(character: &Character) -> u8 {
-        let mut robustness = 2 + u8::from(character.attributes.kon) / 2;
+    fn calc_robustness(character: &Character, rules: &RuleSet) -> u8 {
+        let mut robustness = rules
+            .base_robustness
+            .saturating_add(u8::from(character.attributes.kon) / 2);
         robustness =
             robustness.saturating_add_signed(character.passive_modifiers.robustness.into());
+        robustness = robustness.saturating_add_signed(character.edges.passive_bonus().robustness);
+        robustness = robustness.saturating_add_signed(character.creature.size());
         robustness
     }
 
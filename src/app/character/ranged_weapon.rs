@@ -0,0 +1,102 @@
+use crate::app::widgets::{self, DrawInfo, IntStat, ValueSelector as _, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+use super::weapon::{Damage, DamageName};
+
+/// Bow, crossbow or similar, used until the opponent is in melee or the ammo runs out
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct RangedWeapon {
+    /// damage is rolled with two dice
+    pub(crate) damage: Damage,
+    pub(crate) piercing: IntStat<0, 3>,
+    pub(crate) ammo: IntStat<0, 30>,
+    /// actions needed to reload after each shot
+    pub(crate) reload: IntStat<0, 3>,
+}
+
+impl RangedWeapon {
+    pub fn active(&self) -> bool {
+        self.damage != Damage::None
+    }
+
+    pub fn ammo(&self) -> u8 {
+        i8::from(self.ammo).try_into().unwrap_or(0)
+    }
+
+    pub fn reload_actions(&self) -> u8 {
+        i8::from(self.reload).try_into().unwrap_or(0)
+    }
+}
+
+impl Drawable for RangedWeapon {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Fernkampfwaffe");
+
+        ui.heading("Fernkampfwaffe");
+        grid.show(ui, |ui| {
+            self.damage.draw(DamageName::Ranged, selection, sim, ui);
+            ui.end_row();
+            self.piercing.draw(RangedInfo::Piercing, selection, sim, ui);
+            ui.end_row();
+            self.ammo.draw(RangedInfo::Ammo, selection, sim, ui);
+            ui.end_row();
+            self.reload.draw(RangedInfo::Reload, selection, sim, ui);
+            ui.end_row();
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RangedInfo {
+    Piercing,
+    Ammo,
+    Reload,
+}
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for RangedInfo {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Piercing => "Panzerbrechend",
+            Self::Ammo => "Munition",
+            Self::Reload => "Nachladen",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        match self {
+            Self::Piercing => None,
+            Self::Ammo => Some("Ohne Munition wird in den Nahkampf gewechselt."),
+            Self::Reload => Some("Aktionen zum Nachladen nach jedem Schuss, z.B. 1 für Armbrüste."),
+        }
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Piercing => Box::new(|c| c.ranged_weapon.piercing.decrement()),
+            Self::Ammo => Box::new(|c| c.ranged_weapon.ammo.decrement()),
+            Self::Reload => Box::new(|c| c.ranged_weapon.reload.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Piercing => Box::new(|c| c.ranged_weapon.piercing.increment()),
+            Self::Ammo => Box::new(|c| c.ranged_weapon.ammo.increment()),
+            Self::Reload => Box::new(|c| c.ranged_weapon.reload.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let value = value.into();
+        let modification: simulator::CharModFunc = match self {
+            Self::Piercing => Box::new(move |c| c.ranged_weapon.piercing.set(value)),
+            Self::Ammo => Box::new(move |c| c.ranged_weapon.ammo.set(value)),
+            Self::Reload => Box::new(move |c| c.ranged_weapon.reload.set(value)),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::app::widgets::{self, BoolStat, DrawInfo, IntStat, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+
+type Advances = IntStat<0, 40>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Experience {
+    pub(crate) advances: Advances,
+    /// extras roll without wild die, get no bennies and are out at the first wound
+    #[serde(default = "wild_card_default")]
+    pub(crate) wild_card: BoolStat,
+}
+
+fn wild_card_default() -> BoolStat {
+    BoolStat::from(true)
+}
+
+impl Default for Experience {
+    fn default() -> Self {
+        Self {
+            advances: Advances::default(),
+            wild_card: wild_card_default(),
+        }
+    }
+}
+
+impl Experience {
+    pub fn is_wild_card(&self) -> bool {
+        self.wild_card.is_set()
+    }
+
+    pub fn rank(&self) -> Rank {
+        Rank::from_advances(i8::from(self.advances).cast_unsigned())
+    }
+}
+
+impl Drawable for Experience {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Erfahrung");
+
+        ui.heading("Erfahrung");
+        grid.show(ui, |ui| {
+            // advances have no effect on the fight, so there is no gradient to show
+            ui.label("Steigerungen");
+            let slider =
+                egui::Slider::new(self.advances.inner_mut(), Advances::min()..=Advances::max());
+            ui.add(slider);
+            ui.end_row();
+            ui.label("Rang");
+            ui.label(self.rank().as_str());
+            ui.end_row();
+            self.wild_card.draw(WildCardInfo, selection, sim, ui);
+            ui.end_row();
+        });
+    }
+}
+
+struct WildCardInfo;
+
+impl DrawInfo<BoolStat> for WildCardInfo {
+    fn as_str(&self) -> &'static str {
+        "Wildcard"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Wildcards würfeln mit Wild Die und haben Bennies. Statisten würfeln ohne Wild Die, \
+            haben keine Bennies und fallen bei der ersten Wunde aus.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.experience.wild_card.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.experience.wild_card.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.experience.wild_card.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Novice,
+    Seasoned,
+    Veteran,
+    Heroic,
+    Legend,
+}
+
+impl Rank {
+    pub fn from_advances(advances: u8) -> Self {
+        match advances {
+            0..=3 => Self::Novice,
+            4..=7 => Self::Seasoned,
+            8..=11 => Self::Veteran,
+            12..=15 => Self::Heroic,
+            _ => Self::Legend,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Novice => "Anfänger",
+            Self::Seasoned => "Fortgeschritten",
+            Self::Veteran => "Veteran",
+            Self::Heroic => "Held",
+            Self::Legend => "Legende",
+        }
+    }
+}
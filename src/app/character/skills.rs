@@ -9,6 +9,26 @@ use super::Drawable;
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Skills {
     pub(crate) kampfen: Skill,
+    #[serde(default)]
+    pub(crate) zaubern: Skill,
+    #[serde(default)]
+    pub(crate) heilkunde: Skill,
+    #[serde(default)]
+    pub(crate) schiessen: Skill,
+    #[serde(default)]
+    pub(crate) wahrnehmung: Skill,
+}
+
+impl Skills {
+    pub fn get(&self, name: SkillName) -> Skill {
+        match name {
+            SkillName::Kämpfen => self.kampfen,
+            SkillName::Zaubern => self.zaubern,
+            SkillName::Heilkunde => self.heilkunde,
+            SkillName::Schießen => self.schiessen,
+            SkillName::Wahrnehmung => self.wahrnehmung,
+        }
+    }
 }
 
 impl Drawable for Skills {
@@ -19,6 +39,16 @@ impl Drawable for Skills {
         grid.show(ui, |ui| {
             self.kampfen.draw(SkillName::Kämpfen, selection, sim, ui);
             ui.end_row();
+            self.zaubern.draw(SkillName::Zaubern, selection, sim, ui);
+            ui.end_row();
+            self.heilkunde
+                .draw(SkillName::Heilkunde, selection, sim, ui);
+            ui.end_row();
+            self.schiessen.draw(SkillName::Schießen, selection, sim, ui);
+            ui.end_row();
+            self.wahrnehmung
+                .draw(SkillName::Wahrnehmung, selection, sim, ui);
+            ui.end_row();
         });
     }
 }
@@ -26,18 +56,30 @@ impl Drawable for Skills {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
 pub enum SkillName {
     Kämpfen,
+    Zaubern,
+    Heilkunde,
+    Schießen,
+    Wahrnehmung,
 }
 
 impl DrawInfo<Skill> for SkillName {
     fn as_str(&self) -> &'static str {
         match self {
             SkillName::Kämpfen => "Kämpfen",
+            SkillName::Zaubern => "Zaubern",
+            SkillName::Heilkunde => "Heilkunde Wunden",
+            SkillName::Schießen => "Schießen",
+            SkillName::Wahrnehmung => "Wahrnehmung",
         }
     }
 
     fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
         let modification: simulator::CharModFunc = match self {
             SkillName::Kämpfen => Box::new(|c| c.skills.kampfen.decrement()),
+            SkillName::Zaubern => Box::new(|c| c.skills.zaubern.decrement()),
+            SkillName::Heilkunde => Box::new(|c| c.skills.heilkunde.decrement()),
+            SkillName::Schießen => Box::new(|c| c.skills.schiessen.decrement()),
+            SkillName::Wahrnehmung => Box::new(|c| c.skills.wahrnehmung.decrement()),
         };
         simulator::CharModification::new(selection, modification)
     }
@@ -45,6 +87,10 @@ impl DrawInfo<Skill> for SkillName {
     fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
         let modification: simulator::CharModFunc = match self {
             SkillName::Kämpfen => Box::new(|c| c.skills.kampfen.increment()),
+            SkillName::Zaubern => Box::new(|c| c.skills.zaubern.increment()),
+            SkillName::Heilkunde => Box::new(|c| c.skills.heilkunde.increment()),
+            SkillName::Schießen => Box::new(|c| c.skills.schiessen.increment()),
+            SkillName::Wahrnehmung => Box::new(|c| c.skills.wahrnehmung.increment()),
         };
         simulator::CharModification::new(selection, modification)
     }
@@ -52,6 +98,10 @@ impl DrawInfo<Skill> for SkillName {
     fn mod_set(&self, selection: app::CharSelection, value: Skill) -> CharModification {
         let modification: simulator::CharModFunc = match self {
             SkillName::Kämpfen => Box::new(move |c| c.skills.kampfen = value),
+            SkillName::Zaubern => Box::new(move |c| c.skills.zaubern = value),
+            SkillName::Heilkunde => Box::new(move |c| c.skills.heilkunde = value),
+            SkillName::Schießen => Box::new(move |c| c.skills.schiessen = value),
+            SkillName::Wahrnehmung => Box::new(move |c| c.skills.wahrnehmung = value),
         };
         simulator::CharModification::new(selection, modification)
     }
@@ -64,6 +114,8 @@ impl DrawInfo<Skill> for SkillName {
     Copy,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     strum_macros::EnumIter,
     serde::Serialize,
@@ -0,0 +1,444 @@
+use crate::app::widgets::{self, BoolStat, DrawInfo, IntStat, ValueSelector, ValueSlider as _};
+use crate::simulator::{CharModification, Simulator};
+use crate::{app, simulator};
+
+use super::Drawable;
+
+/// When a char uses its abilities and consumables during a fight
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Tactics {
+    /// allies (and the char itself) below this amount of life get healed
+    pub(crate) heal_threshold: IntStat<0, 40>,
+    /// a strength elixir is drunk below this amount of life
+    #[serde(default)]
+    pub(crate) elixir_threshold: IntStat<0, 40>,
+    #[serde(default)]
+    pub(crate) throw_knives: BoolStat,
+    /// wait and interrupt the turn of the target
+    #[serde(default)]
+    pub(crate) hold: BoolStat,
+    /// only hold the action when a joker was drawn
+    #[serde(default)]
+    pub(crate) hold_joker: BoolStat,
+    /// round in which the char joins the fight, 0 and 1 both mean from the start
+    #[serde(default)]
+    pub(crate) arrival: IntStat<0, 10>,
+    /// only attack with reach weapons while allies in the front rank are standing
+    #[serde(default)]
+    pub(crate) back_rank: BoolStat,
+    /// leave melee even if adjacent opponents get a free attack
+    #[serde(default)]
+    pub(crate) disengage: BoolStat,
+    /// gets protected by the bodyguards of the group
+    #[serde(default)]
+    pub(crate) ward: BoolStat,
+    #[serde(default)]
+    pub(crate) protection: Protection,
+}
+
+impl Tactics {
+    pub fn heal_threshold(&self) -> u8 {
+        i8::from(self.heal_threshold).try_into().unwrap_or(0)
+    }
+
+    pub fn elixir_threshold(&self) -> u8 {
+        i8::from(self.elixir_threshold).try_into().unwrap_or(0)
+    }
+
+    pub fn arrival(&self) -> u32 {
+        i8::from(self.arrival).try_into().unwrap_or(0)
+    }
+}
+
+impl Drawable for Tactics {
+    fn draw(&mut self, selection: app::CharSelection, sim: &mut Simulator, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Taktik");
+
+        ui.heading("Taktik");
+        grid.show(ui, |ui| {
+            self.heal_threshold
+                .draw(Threshold::Heal, selection, sim, ui);
+            ui.end_row();
+            self.elixir_threshold
+                .draw(Threshold::Elixir, selection, sim, ui);
+            ui.end_row();
+            self.throw_knives.draw(ThrowKnives, selection, sim, ui);
+            ui.end_row();
+            self.hold.draw(Hold, selection, sim, ui);
+            ui.end_row();
+            self.hold_joker.draw(HoldJoker, selection, sim, ui);
+            ui.end_row();
+            self.arrival.draw(Arrival, selection, sim, ui);
+            ui.end_row();
+            self.back_rank.draw(BackRank, selection, sim, ui);
+            ui.end_row();
+            self.disengage.draw(Disengage, selection, sim, ui);
+            ui.end_row();
+            self.ward.draw(Ward, selection, sim, ui);
+            ui.end_row();
+            self.protection.draw(Protect, selection, sim, ui);
+            ui.end_row();
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Threshold {
+    Heal,
+    Elixir,
+}
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for Threshold {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Heal => "Heilen unter LeP",
+            Self::Elixir => "Stärkeelixier unter LeP",
+        }
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Self::Heal => {
+                "Verbündete (oder sich selbst) mit weniger LeP mit Heilkunde Wunden oder \
+                Heiltränken heilen, statt anzugreifen."
+            }
+            Self::Elixir => "Ein Stärkeelixier trinken, sobald man weniger LeP hat.",
+        };
+        Some(description)
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Heal => Box::new(|c| c.tactics.heal_threshold.decrement()),
+            Self::Elixir => Box::new(|c| c.tactics.elixir_threshold.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Heal => Box::new(|c| c.tactics.heal_threshold.increment()),
+            Self::Elixir => Box::new(|c| c.tactics.elixir_threshold.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Heal => Box::new(move |c| c.tactics.heal_threshold.set(value.into())),
+            Self::Elixir => Box::new(move |c| c.tactics.elixir_threshold.set(value.into())),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ThrowKnives;
+
+impl DrawInfo<BoolStat> for ThrowKnives {
+    fn as_str(&self) -> &'static str {
+        "Wurfmesser werfen"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Wurfmesser werfen, solange der Gegner nicht im Nahkampf ist.")
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.throw_knives.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.throw_knives.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.tactics.throw_knives.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hold;
+
+impl DrawInfo<BoolStat> for Hold {
+    fn as_str(&self) -> &'static str {
+        "Abwarten"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Die Aktion abwarten und den Zug des Gegners mit einer vergleichenden Ges-Probe \
+            unterbrechen. Wird der Gegner dabei angeschlagen, verliert er seine Aktion. \
+            Fällt der Gegner vorher, wird sofort gehandelt.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.hold.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.hold.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(move |c| c.tactics.hold.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HoldJoker;
+
+impl DrawInfo<BoolStat> for HoldJoker {
+    fn as_str(&self) -> &'static str {
+        "Mit Joker abwarten"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Die Aktion nur abwarten, wenn ein Joker gezogen wurde.")
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.hold_joker.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.hold_joker.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.tactics.hold_joker.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Arrival;
+
+impl<const MIN: i8, const MAX: i8> DrawInfo<IntStat<MIN, MAX>> for Arrival {
+    fn as_str(&self) -> &'static str {
+        "Ankunft in Runde"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Als Verstärkung erst zu Beginn dieser Runde in den Kampf eingreifen, z.B. \
+            herbeieilende Wachen. 0 oder 1: von Anfang an dabei.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.arrival.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.arrival.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: IntStat<MIN, MAX>) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.tactics.arrival.set(value.into()));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BackRank;
+
+impl DrawInfo<BoolStat> for BackRank {
+    fn as_str(&self) -> &'static str {
+        "Hintere Reihe"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Hinter den Verbündeten der vorderen Reihe stehen. Greift nur mit Reichweitenwaffen \
+            an und wird erst angegriffen, wenn die vordere Reihe gefallen ist.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.back_rank.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.back_rank.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.tactics.back_rank.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Disengage;
+
+impl DrawInfo<BoolStat> for Disengage {
+    fn as_str(&self) -> &'static str {
+        "Absetzen"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Nach dem Angriff aus dem Nahkampf zurückweichen, auch wenn benachbarte, nicht \
+            angeschlagene Gegner dabei einen freien Angriff erhalten. Ohne diese Taktik wird \
+            nur mit Erstschlag zurückgewichen und nur, wenn kein Gegner angreifen kann. Mit \
+            einer Reichweitenwaffe gibt es keine freien Angriffe.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.disengage.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.disengage.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc =
+            Box::new(move |c| c.tactics.disengage.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Ward;
+
+impl DrawInfo<BoolStat> for Ward {
+    fn as_str(&self) -> &'static str {
+        "Schützling"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Wird von den Leibwachen der Gruppe beschützt. Die Auswertung zeigt, wie oft alle \
+            Schützlinge der linken Gruppe überleben.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.ward.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.ward.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: BoolStat) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(move |c| c.tactics.ward.set(value));
+        simulator::CharModification::new(selection, modification)
+    }
+}
+
+/// How a bodyguard protects the wards of its group
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Protection {
+    #[default]
+    None,
+    /// attacks on a ward hit the bodyguard instead
+    Intercept,
+    /// attack whoever is in melee with a ward
+    Engage,
+}
+
+impl ValueSelector for Protection {
+    type Info = Protect;
+
+    fn possible_values() -> impl Iterator<Item = Self> {
+        use strum::IntoEnumIterator as _;
+
+        Self::iter()
+    }
+
+    fn as_str(&self, _info: &Self::Info) -> &'static str {
+        match self {
+            Self::None => "Nein",
+            Self::Intercept => "Abfangen",
+            Self::Engage => "Angreifer stellen",
+        }
+    }
+}
+
+impl Protection {
+    fn decrement(&mut self) {
+        let new = match self {
+            Self::None => Self::None,
+            Self::Intercept => Self::None,
+            Self::Engage => Self::Intercept,
+        };
+        *self = new;
+    }
+
+    fn increment(&mut self) {
+        let new = match self {
+            Self::None => Self::Intercept,
+            Self::Intercept => Self::Engage,
+            Self::Engage => Self::Engage,
+        };
+        *self = new;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Protect;
+
+impl DrawInfo<Protection> for Protect {
+    fn as_str(&self) -> &'static str {
+        "Beschützen"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Als Leibwache die Schützlinge der Gruppe beschützen. Abfangen: Angriffe auf einen \
+            Schützling treffen stattdessen die Leibwache. Angreifer stellen: wer im Nahkampf mit \
+            einem Schützling ist, wird zuerst angegriffen.",
+        )
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.protection.decrement());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(|c| c.tactics.protection.increment());
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: Protection) -> CharModification {
+        let modification: simulator::CharModFunc = Box::new(move |c| c.tactics.protection = value);
+        simulator::CharModification::new(selection, modification)
+    }
+}
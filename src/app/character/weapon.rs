@@ -5,6 +5,7 @@ use crate::simulator::{CharModification, Simulator};
 use crate::{app, simulator};
 
 use super::Drawable;
+use super::damage_over_time::{Carrier, DamageOverTime};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Weapon<const SECONDARY: bool> {
@@ -14,6 +15,11 @@ pub struct Weapon<const SECONDARY: bool> {
     pub(crate) piercing: IntStat<0, 3>,
     pub(crate) reach: IntStat<0, 2>,
     pub(crate) more_crit: BoolStat,
+    #[serde(default)]
+    pub(crate) damage_over_time: DamageOverTime,
+    /// picks the fumble table
+    #[serde(default)]
+    pub(crate) kind: WeaponKind,
 }
 
 impl<const SECONDARY: bool> Weapon<SECONDARY> {
@@ -31,6 +37,8 @@ impl<const SECONDARY: bool> Default for Weapon<SECONDARY> {
             piercing: IntStat::default(),
             reach: IntStat::default(),
             more_crit: BoolStat::default(),
+            damage_over_time: DamageOverTime::default(),
+            kind: WeaponKind::default(),
         }
     }
 }
@@ -45,6 +53,8 @@ impl<const SECONDARY: bool> Drawable for Weapon<SECONDARY> {
             ui.end_row();
             self.damage.draw(self.damage_name(), selection, sim, ui);
             ui.end_row();
+            self.kind.draw(self.kind_info(), selection, sim, ui);
+            ui.end_row();
             self.bonus_damage
                 .draw(ModifierInfo::<SECONDARY>::BonusDamage, selection, sim, ui);
             ui.end_row();
@@ -57,6 +67,8 @@ impl<const SECONDARY: bool> Drawable for Weapon<SECONDARY> {
             self.more_crit
                 .draw(MoreCritInfo::<SECONDARY>, selection, sim, ui);
             ui.end_row();
+            self.damage_over_time
+                .draw_rows(self.carrier(), selection, sim, ui);
         });
     }
 }
@@ -79,6 +91,22 @@ impl<const SECONDARY: bool> Weapon<SECONDARY> {
         }
     }
 
+    fn kind_info(&self) -> KindInfo {
+        if SECONDARY {
+            KindInfo::Secondary
+        } else {
+            KindInfo::Primary
+        }
+    }
+
+    fn carrier(&self) -> Carrier {
+        if SECONDARY {
+            Carrier::Secondary
+        } else {
+            Carrier::Primary
+        }
+    }
+
     fn draw_active(
         &mut self,
         selection: app::CharSelection,
@@ -119,17 +147,30 @@ impl<const SECONDARY: bool> Weapon<SECONDARY> {
 pub enum DamageName {
     Primary,
     Secondary,
+    Ranged,
+    /// index of the natural attack
+    Natural(usize),
 }
 
 impl DrawInfo<Damage> for DamageName {
     fn as_str(&self) -> &'static str {
-        "Schaden"
+        match self {
+            DamageName::Primary | DamageName::Secondary => "Schaden",
+            DamageName::Ranged => "Schaden (2 Würfel)",
+            DamageName::Natural(0) => "Schaden Angriff 1",
+            DamageName::Natural(1) => "Schaden Angriff 2",
+            DamageName::Natural(_) => "Schaden Angriff 3",
+        }
     }
 
     fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
         let modification: simulator::CharModFunc = match self {
             DamageName::Primary => Box::new(|c| c.weapon.damage.decrement()),
             DamageName::Secondary => Box::new(|c| c.secondary_weapon.damage.decrement()),
+            DamageName::Ranged => Box::new(|c| c.ranged_weapon.damage.decrement()),
+            &DamageName::Natural(index) => {
+                Box::new(move |c| c.natural_attacks.get_mut(index).damage.decrement())
+            }
         };
         simulator::CharModification::new(selection, modification)
     }
@@ -138,6 +179,10 @@ impl DrawInfo<Damage> for DamageName {
         let modification: simulator::CharModFunc = match self {
             DamageName::Primary => Box::new(|c| c.weapon.damage.increment()),
             DamageName::Secondary => Box::new(|c| c.secondary_weapon.damage.increment()),
+            DamageName::Ranged => Box::new(|c| c.ranged_weapon.damage.increment()),
+            &DamageName::Natural(index) => {
+                Box::new(move |c| c.natural_attacks.get_mut(index).damage.increment())
+            }
         };
         simulator::CharModification::new(selection, modification)
     }
@@ -146,6 +191,10 @@ impl DrawInfo<Damage> for DamageName {
         let modification: simulator::CharModFunc = match self {
             DamageName::Primary => Box::new(move |c| c.weapon.damage = value),
             DamageName::Secondary => Box::new(move |c| c.secondary_weapon.damage = value),
+            DamageName::Ranged => Box::new(move |c| c.ranged_weapon.damage = value),
+            &DamageName::Natural(index) => {
+                Box::new(move |c| c.natural_attacks.get_mut(index).damage = value)
+            }
         };
         simulator::CharModification::new(selection, modification)
     }
@@ -206,7 +255,7 @@ impl ValueSelector for Damage {
 }
 
 impl Damage {
-    fn decrement(&mut self) {
+    pub fn decrement(&mut self) {
         let new = match self {
             Self::None => Self::None,
             Self::W4 => Self::None,
@@ -218,7 +267,7 @@ impl Damage {
         *self = new;
     }
 
-    fn increment(&mut self) {
+    pub fn increment(&mut self) {
         let new = match self {
             Self::None => Self::W4,
             Self::W4 => Self::W6,
@@ -323,3 +372,99 @@ impl<const SECONDARY: bool> DrawInfo<BoolStat> for MoreCritInfo<SECONDARY> {
         simulator::CharModification::new(selection, modification)
     }
 }
+
+/// The kind of a melee weapon, each has its own fumble table
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum WeaponKind {
+    #[default]
+    Blade,
+    Blunt,
+    Polearm,
+}
+
+impl ValueSelector for WeaponKind {
+    type Info = KindInfo;
+
+    fn possible_values() -> impl Iterator<Item = Self> {
+        Self::iter()
+    }
+
+    fn as_str(&self, _info: &Self::Info) -> &'static str {
+        match self {
+            Self::Blade => "Klinge",
+            Self::Blunt => "Hiebwaffe",
+            Self::Polearm => "Stangenwaffe",
+        }
+    }
+}
+
+impl WeaponKind {
+    fn decrement(&mut self) {
+        let new = match self {
+            Self::Blade => Self::Blade,
+            Self::Blunt => Self::Blade,
+            Self::Polearm => Self::Blunt,
+        };
+        *self = new;
+    }
+
+    fn increment(&mut self) {
+        let new = match self {
+            Self::Blade => Self::Blunt,
+            Self::Blunt => Self::Polearm,
+            Self::Polearm => Self::Polearm,
+        };
+        *self = new;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KindInfo {
+    Primary,
+    Secondary,
+}
+
+impl DrawInfo<WeaponKind> for KindInfo {
+    fn as_str(&self) -> &'static str {
+        "Waffenart"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Bestimmt die Patzertabelle im Nahkampf.")
+    }
+
+    fn mod_dec(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Primary => Box::new(|c| c.weapon.kind.decrement()),
+            Self::Secondary => Box::new(|c| c.secondary_weapon.kind.decrement()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_inc(&self, selection: app::CharSelection) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Primary => Box::new(|c| c.weapon.kind.increment()),
+            Self::Secondary => Box::new(|c| c.secondary_weapon.kind.increment()),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+
+    fn mod_set(&self, selection: app::CharSelection, value: WeaponKind) -> CharModification {
+        let modification: simulator::CharModFunc = match self {
+            Self::Primary => Box::new(move |c| c.weapon.kind = value),
+            Self::Secondary => Box::new(move |c| c.secondary_weapon.kind = value),
+        };
+        simulator::CharModification::new(selection, modification)
+    }
+}
@@ -1,13 +1,20 @@
+use strum::IntoEnumIterator as _;
+
 use crate::app::{character::Character, dnd, widgets};
 
 #[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize)]
 pub struct Group {
     chars: Vec<Character>,
+    #[serde(default)]
+    bennies: GroupBennies,
+    /// all extras of the group draw one initiative card
+    #[serde(default)]
+    shared_initiative: bool,
 }
 
 impl Group {
-    pub fn into_vec(self) -> Vec<Character> {
-        self.chars
+    pub fn into_parts(self) -> (Vec<Character>, GroupBennies, bool) {
+        (self.chars, self.bennies, self.shared_initiative)
     }
 
     #[must_use]
@@ -21,6 +28,12 @@ impl Group {
                 if let Some(button_action) = self.draw_menu_buttons(ui) {
                     action = Some(button_action);
                 }
+                self.bennies.draw(ui);
+                ui.checkbox(&mut self.shared_initiative, "Gemeinsame Initiative")
+                    .on_hover_text(
+                        "Alle Statisten der Gruppe ziehen zusammen eine Initiativkarte und \
+                        handeln direkt nacheinander",
+                    );
 
                 ui.add_space(15.0);
 
@@ -134,6 +147,81 @@ impl Group {
     }
 }
 
+/// Bennies of the GM or the players, shared by the wild cards of a group
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub struct GroupBennies {
+    pub(crate) count: u8,
+    pub(crate) mode: PoolMode,
+}
+
+impl GroupBennies {
+    /// bennies in the shared pool
+    pub fn shared(&self) -> u8 {
+        match self.mode {
+            PoolMode::Individual => 0,
+            PoolMode::Shared | PoolMode::Both => self.count,
+        }
+    }
+
+    /// the wild cards use their own bennies
+    pub fn individual(&self) -> bool {
+        self.mode != PoolMode::Shared
+    }
+
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Gruppenbennies").on_hover_text(
+                "Bennies, aus denen alle Wildcards der Gruppe nach ihren Benny-Regeln schöpfen",
+            );
+            ui.add_enabled(
+                self.mode != PoolMode::Individual,
+                egui::DragValue::new(&mut self.count).range(0..=20),
+            );
+            for mode in PoolMode::iter() {
+                ui.selectable_value(&mut self.mode, mode, mode.as_str())
+                    .on_hover_text(mode.description());
+            }
+        });
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum_macros::EnumIter,
+)]
+pub enum PoolMode {
+    #[default]
+    Individual,
+    Shared,
+    Both,
+}
+
+impl PoolMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Individual => "Eigene",
+            Self::Shared => "Gemeinsam",
+            Self::Both => "Beide",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Individual => "Jede Wildcard nutzt nur ihre eigenen Bennies.",
+            Self::Shared => "Die Wildcards nutzen nur die Gruppenbennies.",
+            Self::Both => "Die Wildcards nutzen erst ihre eigenen, dann die Gruppenbennies.",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum GroupAction {
     New,
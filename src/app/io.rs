@@ -4,7 +4,9 @@ use anyhow::{Context, Result, bail};
 
 use crate::app::GroupId;
 
-use super::character::Character;
+use super::character::{Character, Violations};
+use super::rules::RuleSet;
+use super::scenario::Scenario;
 
 pub struct IoThread {
     thread: Option<thread::JoinHandle<()>>,
@@ -72,15 +74,57 @@ impl IoThread {
                     }
                 }
                 Ok(IoRequest::Load(group_id)) => {
-                    let new_char = match Self::load() {
-                        Ok(Some(new_char)) => new_char,
+                    let (new_char, violations) = match Self::load() {
+                        Ok(Some(loaded)) => loaded,
                         Ok(None) => continue 'thread_loop,
                         Err(err) => {
                             log::error!("failed to load character: {err:?}");
                             continue 'thread_loop;
                         }
                     };
-                    let Ok(()) = response.send(IoResponse::CharLoaded(group_id, new_char)) else {
+                    let Ok(()) =
+                        response.send(IoResponse::CharLoaded(group_id, new_char, violations))
+                    else {
+                        break 'thread_loop;
+                    };
+                }
+                Ok(IoRequest::SaveRules(rules)) => {
+                    if let Err(err) = Self::save_rules(&rules) {
+                        log::error!("failed to save rules: {err:?}");
+                    } else {
+                        log::debug!("rules saved");
+                    }
+                }
+                Ok(IoRequest::LoadRules) => {
+                    let rules = match Self::load_rules() {
+                        Ok(Some(rules)) => rules,
+                        Ok(None) => continue 'thread_loop,
+                        Err(err) => {
+                            log::error!("failed to load rules: {err:?}");
+                            continue 'thread_loop;
+                        }
+                    };
+                    let Ok(()) = response.send(IoResponse::RulesLoaded(rules)) else {
+                        break 'thread_loop;
+                    };
+                }
+                Ok(IoRequest::SaveScenario(scenario)) => {
+                    if let Err(err) = Self::save_scenario(&scenario) {
+                        log::error!("failed to save scenario: {err:?}");
+                    } else {
+                        log::debug!("scenario saved");
+                    }
+                }
+                Ok(IoRequest::LoadScenario) => {
+                    let scenario = match Self::load_scenario() {
+                        Ok(Some(scenario)) => scenario,
+                        Ok(None) => continue 'thread_loop,
+                        Err(err) => {
+                            log::error!("failed to load scenario: {err:?}");
+                            continue 'thread_loop;
+                        }
+                    };
+                    let Ok(()) = response.send(IoResponse::ScenarioLoaded(scenario)) else {
                         break 'thread_loop;
                     };
                 }
@@ -106,26 +150,99 @@ impl IoThread {
         Ok(())
     }
 
-    fn load() -> Result<Option<Character>> {
+    fn load() -> Result<Option<(Character, Violations)>> {
         let Some(path) = create_file_dialog().set_title("Char laden").pick_file() else {
             log::debug!("load file dialog was canceled");
             return Ok(None);
         };
 
         let data = fs::read(path).context("failed to read from file")?;
-        let new_char =
-            serde_json::from_slice(&data).context("failed to convert JSON to character")?;
-        Ok(Some(new_char))
+        Self::parse_char(&data).map(Some)
+    }
+
+    /// the char and the prerequisites of its edges it doesn't meet
+    fn parse_char(data: &[u8]) -> Result<(Character, Violations)> {
+        let new_char: Character =
+            serde_json::from_slice(data).context("failed to convert JSON to character")?;
+        let violations = Violations::new(&new_char);
+        Ok((new_char, violations))
+    }
+
+    fn save_rules(rules: &RuleSet) -> Result<()> {
+        let rules_serialized =
+            serde_json::to_vec_pretty(rules).context("failed to convert rules to JSON format")?;
+
+        let Some(path) = create_file_dialog()
+            .set_title("Regeln speichern")
+            .set_file_name("Hausregeln.json")
+            .save_file()
+        else {
+            log::debug!("save file dialog was canceled");
+            return Ok(());
+        };
+        fs::write(&path, &rules_serialized).context("failed to write to file")?;
+        Ok(())
+    }
+
+    fn load_rules() -> Result<Option<RuleSet>> {
+        let Some(path) = create_file_dialog().set_title("Regeln laden").pick_file() else {
+            log::debug!("load file dialog was canceled");
+            return Ok(None);
+        };
+
+        let data = fs::read(path).context("failed to read from file")?;
+        let mut rules: RuleSet =
+            serde_json::from_slice(&data).context("failed to convert JSON to rules")?;
+        rules.clamp_values();
+        Ok(Some(rules))
+    }
+
+    fn save_scenario(scenario: &Scenario) -> Result<()> {
+        let scenario_serialized = serde_json::to_vec_pretty(scenario)
+            .context("failed to convert scenario to JSON format")?;
+
+        let Some(path) = create_file_dialog()
+            .set_title("Szenario speichern")
+            .set_file_name("Szenario.json")
+            .save_file()
+        else {
+            log::debug!("save file dialog was canceled");
+            return Ok(());
+        };
+        fs::write(&path, &scenario_serialized).context("failed to write to file")?;
+        Ok(())
+    }
+
+    fn load_scenario() -> Result<Option<Scenario>> {
+        let Some(path) = create_file_dialog().set_title("Szenario laden").pick_file() else {
+            log::debug!("load file dialog was canceled");
+            return Ok(None);
+        };
+
+        let data = fs::read(path).context("failed to read from file")?;
+        let scenario =
+            serde_json::from_slice(&data).context("failed to convert JSON to scenario")?;
+        Ok(Some(scenario))
     }
 }
 
 pub enum IoRequest {
     Save(Character),
     Load(GroupId),
+    SaveRules(RuleSet),
+    LoadRules,
+    SaveScenario(Scenario),
+    LoadScenario,
 }
 
+#[allow(
+    clippy::enum_variant_names,
+    reason = "every response is something loaded"
+)]
 pub enum IoResponse {
-    CharLoaded(GroupId, Character),
+    CharLoaded(GroupId, Character, Violations),
+    RulesLoaded(RuleSet),
+    ScenarioLoaded(Scenario),
 }
 
 fn get_char_dir() -> Result<PathBuf> {
@@ -156,3 +273,24 @@ fn create_file_dialog() -> rfd::FileDialog {
     }
     dialog.add_filter("json", &["json"]).add_filter("*", &["*"])
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::EdgeId;
+
+    use super::*;
+
+    #[test]
+    fn test_loading_reports_violations() {
+        let mut character = Character::default();
+        let data = serde_json::to_vec(&character).unwrap();
+        let (_loaded, violations) = IoThread::parse_char(&data).unwrap();
+        assert!(violations.is_empty());
+
+        character.edges.increment(EdgeId::Blitzhieb);
+        let data = serde_json::to_vec(&character).unwrap();
+        let (loaded, violations) = IoThread::parse_char(&data).unwrap();
+        assert!(!violations.is_empty());
+        assert_eq!(loaded, character);
+    }
+}
@@ -0,0 +1,233 @@
+mod fumbles;
+
+use strum::IntoEnumIterator as _;
+
+use crate::app::widgets;
+
+pub use fumbles::{Fumble, FumbleTable, FumbleTables};
+
+/// House rules for all fights
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct RuleSet {
+    /// life of a char before Kon and Wil are added
+    pub(crate) base_life: u8,
+    /// parry of a char before half of kampfen is added
+    pub(crate) base_parry: u8,
+    /// robustness of a char before half of Kon is added
+    pub(crate) base_robustness: u8,
+    /// max bonus for ganging up on someone, edges may lower it
+    pub(crate) gang_up_cap: u8,
+    /// bonus to rolls and damage while holding a joker
+    pub(crate) joker_bonus: u8,
+    /// -1 to all rolls at or below this life
+    pub(crate) light_wound_threshold: u8,
+    /// -3 to all rolls at or below this life
+    pub(crate) heavy_wound_threshold: u8,
+    /// fighters at or below this life are out
+    pub(crate) death_threshold: u8,
+    /// extras are out at their first wound
+    pub(crate) extras_die_at_first_wound: bool,
+    pub(crate) wound_model: WoundModel,
+    pub(crate) fumble_tables: FumbleTables,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            base_life: 24,
+            base_parry: 2,
+            base_robustness: 2,
+            gang_up_cap: 4,
+            joker_bonus: 2,
+            light_wound_threshold: 20,
+            heavy_wound_threshold: 10,
+            death_threshold: 5,
+            extras_die_at_first_wound: true,
+            wound_model: WoundModel::default(),
+            fumble_tables: FumbleTables::default(),
+        }
+    }
+}
+
+impl RuleSet {
+    /// highest value of each number in the settings window
+    const MAX_VALUE: u8 = 40;
+
+    /// loaded rules may hold values the settings window doesn't allow
+    pub fn clamp_values(&mut self) {
+        for value in [
+            &mut self.base_life,
+            &mut self.base_parry,
+            &mut self.base_robustness,
+            &mut self.gang_up_cap,
+            &mut self.joker_bonus,
+            &mut self.light_wound_threshold,
+            &mut self.heavy_wound_threshold,
+            &mut self.death_threshold,
+        ] {
+            *value = (*value).min(Self::MAX_VALUE);
+        }
+    }
+
+    pub fn draw(&mut self, ui: &mut egui::Ui) -> Option<RulesAction> {
+        let mut action = None;
+        ui.horizontal(|ui| {
+            let button_size = 40.0;
+            let save = widgets::create_menu_button("💾", "Regeln speichern", button_size, ui);
+            let load = widgets::create_menu_button("📂", "Regeln laden", button_size, ui);
+            let reset = widgets::create_menu_button("↺", "Standardregeln", button_size, ui);
+            if save.clicked() {
+                action = Some(RulesAction::Save);
+            }
+            if load.clicked() {
+                action = Some(RulesAction::Load);
+            }
+            if reset.clicked() {
+                *self = Self::default();
+            }
+        });
+
+        let grid = widgets::create_grid("Hausregeln");
+        grid.show(ui, |ui| {
+            let value = |ui: &mut egui::Ui, label, description, value: &mut u8| {
+                ui.label(label).on_hover_text(description);
+                ui.add(egui::DragValue::new(value).range(0..=Self::MAX_VALUE));
+                ui.end_row();
+            };
+            value(
+                ui,
+                "Basis LeP",
+                "LeP eines Chars, bevor Kon und Wil addiert werden",
+                &mut self.base_life,
+            );
+            value(
+                ui,
+                "Basis PA",
+                "PA eines Chars, bevor Kämpfen / 2 addiert wird",
+                &mut self.base_parry,
+            );
+            value(
+                ui,
+                "Basis ROB",
+                "ROB eines Chars, bevor Kon / 2 addiert wird",
+                &mut self.base_robustness,
+            );
+            value(
+                ui,
+                "Max. Überzahlbonus",
+                "Höchster Bonus für Überzahl, Talente können ihn senken",
+                &mut self.gang_up_cap,
+            );
+            value(
+                ui,
+                "Joker Bonus",
+                "Bonus auf alle Proben und Schaden, solange man einen Joker hat",
+                &mut self.joker_bonus,
+            );
+            value(
+                ui,
+                "Leicht verwundet bei LeP",
+                "-1 auf alle Proben bei höchstens dieser LeP",
+                &mut self.light_wound_threshold,
+            );
+            value(
+                ui,
+                "Schwer verwundet bei LeP",
+                "-3 auf alle Proben bei höchstens dieser LeP",
+                &mut self.heavy_wound_threshold,
+            );
+            value(
+                ui,
+                "Kampfunfähig bei LeP",
+                "Kämpfer mit höchstens dieser LeP sind besiegt",
+                &mut self.death_threshold,
+            );
+
+            ui.label("Statisten")
+                .on_hover_text("Statisten sind bei der ersten Wunde besiegt");
+            ui.checkbox(&mut self.extras_die_at_first_wound, "Erste Wunde besiegt");
+            ui.end_row();
+
+            ui.label("Wunden");
+            ui.horizontal(|ui| {
+                for model in WoundModel::iter() {
+                    ui.selectable_value(&mut self.wound_model, model, model.as_str())
+                        .on_hover_text(model.description());
+                }
+            });
+            ui.end_row();
+        });
+
+        ui.collapsing("Patzertabellen", |ui| {
+            self.fumble_tables.draw(ui);
+        });
+
+        action
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulesAction {
+    Save,
+    Load,
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum_macros::EnumIter,
+)]
+pub enum WoundModel {
+    #[default]
+    LifeThresholds,
+    Locations,
+}
+
+impl WoundModel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::LifeThresholds => "nach LeP",
+            Self::Locations => "Trefferzonen",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::LifeThresholds => "Abzüge auf alle Proben nach verbleibenden LeP, siehe oben.",
+            Self::Locations => {
+                "Jeder Treffer über der Wundschwelle (Kon) verursacht eine Wunde in der \
+                getroffenen Zone. Kopf: schlechtere Initiative, Torso: -1 auf alle Proben, \
+                Arm: -2 auf Angriffe, Bein: Vorrücken kostet die Aktion."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_rules_fall_back_to_defaults() {
+        let rules: RuleSet = serde_json::from_str(r#"{ "joker_bonus": 3 }"#).unwrap();
+        assert_eq!(rules.joker_bonus, 3);
+        assert_eq!(rules.base_life, RuleSet::default().base_life);
+        assert_eq!(rules.wound_model, WoundModel::LifeThresholds);
+    }
+
+    #[test]
+    fn test_loaded_rules_are_clamped() {
+        let mut rules: RuleSet = serde_json::from_str(r#"{ "base_life": 255 }"#).unwrap();
+        rules.clamp_values();
+        assert_eq!(rules.base_life, 40);
+        assert_eq!(rules.base_parry, RuleSet::default().base_parry);
+    }
+}
@@ -0,0 +1,238 @@
+use strum::IntoEnumIterator as _;
+
+use crate::app::character::WeaponKind;
+
+/// Critical failure tables, rolled with 2d6
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct FumbleTables {
+    /// melee with a blade
+    pub(crate) melee: FumbleTable,
+    /// melee with a blunt weapon
+    pub(crate) blunt: FumbleTable,
+    pub(crate) polearm: FumbleTable,
+    /// melee without a weapon or with natural attacks
+    pub(crate) natural: FumbleTable,
+    pub(crate) ranged: FumbleTable,
+}
+
+impl Default for FumbleTables {
+    fn default() -> Self {
+        use Fumble::{
+            Fell, HeavilyInjured, Injured, Tripped, WeaponBroken, WeaponDestroyed, WeaponLost,
+        };
+
+        let melee = FumbleTable([
+            WeaponDestroyed,
+            Fell,
+            Fell,
+            Fell,
+            Tripped,
+            Tripped,
+            Tripped,
+            WeaponLost,
+            WeaponLost,
+            Injured,
+            HeavilyInjured,
+        ]);
+        // the shaft breaks rather than the whole weapon
+        let mut blunt = melee.clone();
+        blunt.0[0] = WeaponBroken;
+        // long weapons get stuck or break, but rarely trip their wielder
+        let polearm = FumbleTable([
+            WeaponBroken,
+            WeaponBroken,
+            Fell,
+            Fell,
+            WeaponLost,
+            WeaponLost,
+            WeaponLost,
+            Tripped,
+            Tripped,
+            Injured,
+            HeavilyInjured,
+        ]);
+        let ranged = FumbleTable([
+            WeaponDestroyed,
+            WeaponDestroyed,
+            // actually it blocks or something, but for the sake of simplicity...
+            WeaponLost,
+            WeaponLost,
+            WeaponLost,
+            WeaponLost,
+            WeaponLost,
+            WeaponLost,
+            WeaponLost,
+            Injured,
+            Injured,
+        ]);
+        Self {
+            natural: melee.clone(),
+            melee,
+            blunt,
+            polearm,
+            ranged,
+        }
+    }
+}
+
+impl FumbleTables {
+    /// table for melee attacks with a weapon
+    pub fn weapon(&self, kind: WeaponKind) -> &FumbleTable {
+        match kind {
+            WeaponKind::Blade => &self.melee,
+            WeaponKind::Blunt => &self.blunt,
+            WeaponKind::Polearm => &self.polearm,
+        }
+    }
+
+    pub fn draw(&mut self, ui: &mut egui::Ui) {
+        egui::Grid::new("Patzertabellen")
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("2W6");
+                ui.label("Klinge");
+                ui.label("Hiebwaffe");
+                ui.label("Stangenwaffe");
+                ui.label("Natürlich");
+                ui.label("Fernkampf");
+                ui.end_row();
+
+                for roll in FumbleTable::ROLLS {
+                    ui.label(roll.to_string());
+                    self.melee.draw_entry("melee", roll, ui);
+                    self.blunt.draw_entry("blunt", roll, ui);
+                    self.polearm.draw_entry("polearm", roll, ui);
+                    self.natural.draw_entry("natural", roll, ui);
+                    self.ranged.draw_entry("ranged", roll, ui);
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+/// The outcomes for the 2d6 results 2 to 12
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct FumbleTable([Fumble; 11]);
+
+impl FumbleTable {
+    const ROLLS: std::ops::RangeInclusive<u8> = 2..=12;
+
+    /// `roll` is the result of 2d6
+    pub fn get(&self, roll: u8) -> Fumble {
+        assert!(Self::ROLLS.contains(&roll), "{roll} is no 2d6 result");
+        self.0[usize::from(roll - 2)]
+    }
+
+    fn draw_entry(&mut self, table: &str, roll: u8, ui: &mut egui::Ui) {
+        let entry = &mut self.0[usize::from(roll - 2)];
+        egui::ComboBox::from_id_salt((table, roll))
+            .selected_text(entry.as_str())
+            .show_ui(ui, |ui| {
+                for fumble in Fumble::iter() {
+                    ui.selectable_value(entry, fumble, fumble.as_str())
+                        .on_hover_text(fumble.description());
+                }
+            });
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum_macros::EnumIter,
+)]
+pub enum Fumble {
+    Nothing,
+    Shaken,
+    Fell,
+    Tripped,
+    WeaponLost,
+    /// the fight goes on without the weapon
+    WeaponBroken,
+    /// treated as defeat
+    WeaponDestroyed,
+    Injured,
+    HeavilyInjured,
+}
+
+impl Fumble {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Nothing => "Nichts",
+            Self::Shaken => "Angeschlagen",
+            Self::Fell => "Gestürzt",
+            Self::Tripped => "Gestolpert",
+            Self::WeaponLost => "Waffe verloren",
+            Self::WeaponBroken => "Waffe zerbrochen",
+            Self::WeaponDestroyed => "Waffe zerstört",
+            Self::Injured => "Selbst verletzt",
+            Self::HeavilyInjured => "Schwer selbst verletzt",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Nothing => "Der Patzer bleibt ohne Folgen.",
+            Self::Shaken => "Man ist angeschlagen.",
+            Self::Fell => "Man ist angeschlagen und liegt am Boden.",
+            Self::Tripped => "Man ist angeschlagen und liegt am Boden.",
+            Self::WeaponLost => {
+                "Die nächste Aktion wird gebraucht, um die Waffe aufzuheben. \
+                Fernkampf: Wechsel in den Nahkampf."
+            }
+            Self::WeaponBroken => "Es wird waffenlos weitergekämpft.",
+            Self::WeaponDestroyed => {
+                "Wird wie eine Niederlage gewertet. Fernkampf: Wechsel in den Nahkampf."
+            }
+            Self::Injured => "Man trifft sich selbst. Fernkampf: Man ist angeschlagen.",
+            Self::HeavilyInjured => {
+                "Man trifft sich selbst mit Steigerung. Fernkampf: Man ist angeschlagen."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_tables() {
+        let tables = FumbleTables::default();
+        assert_eq!(tables.melee.get(2), Fumble::WeaponDestroyed);
+        assert_eq!(tables.melee.get(5), Fumble::Fell);
+        assert_eq!(tables.melee.get(8), Fumble::Tripped);
+        assert_eq!(tables.melee.get(10), Fumble::WeaponLost);
+        assert_eq!(tables.melee.get(12), Fumble::HeavilyInjured);
+        assert_eq!(tables.natural, tables.melee);
+        assert_eq!(tables.weapon(WeaponKind::Blade), &tables.melee);
+        assert_eq!(
+            tables.weapon(WeaponKind::Blunt).get(2),
+            Fumble::WeaponBroken
+        );
+        assert_eq!(tables.weapon(WeaponKind::Blunt).get(3), Fumble::Fell);
+        assert_eq!(
+            tables.weapon(WeaponKind::Polearm).get(7),
+            Fumble::WeaponLost
+        );
+        assert_eq!(tables.ranged.get(3), Fumble::WeaponDestroyed);
+        assert_eq!(tables.ranged.get(4), Fumble::WeaponLost);
+        assert_eq!(tables.ranged.get(11), Fumble::Injured);
+    }
+
+    #[test]
+    fn test_tables_survive_serialization() {
+        let mut tables = FumbleTables::default();
+        tables.melee.0[0] = Fumble::WeaponBroken;
+        let json = serde_json::to_string(&tables).unwrap();
+        let deserialized: FumbleTables = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, tables);
+    }
+}
@@ -0,0 +1,269 @@
+use strum::IntoEnumIterator as _;
+
+use crate::app::widgets;
+
+/// Setup of the fight, in contrast to the rules it changes from encounter to encounter
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Scenario {
+    pub(crate) ambush: Ambush,
+    /// only those failing a Wahrnehmung roll are surprised
+    pub(crate) notice_roll: bool,
+    pub(crate) environment: Environment,
+    pub(crate) objectives: Objectives,
+}
+
+impl Scenario {
+    pub fn draw(&mut self, ui: &mut egui::Ui) -> Option<ScenarioAction> {
+        let mut action = None;
+        ui.horizontal(|ui| {
+            let button_size = 40.0;
+            let save = widgets::create_menu_button("💾", "Szenario speichern", button_size, ui);
+            let load = widgets::create_menu_button("📂", "Szenario laden", button_size, ui);
+            let reset = widgets::create_menu_button("↺", "Standardszenario", button_size, ui);
+            if save.clicked() {
+                action = Some(ScenarioAction::Save);
+            }
+            if load.clicked() {
+                action = Some(ScenarioAction::Load);
+            }
+            if reset.clicked() {
+                *self = Self::default();
+            }
+        });
+
+        let grid = widgets::create_grid("Szenario");
+        grid.show(ui, |ui| {
+            ui.label("Hinterhalt");
+            ui.horizontal(|ui| {
+                for ambush in Ambush::iter() {
+                    ui.selectable_value(&mut self.ambush, ambush, ambush.as_str())
+                        .on_hover_text(ambush.description());
+                }
+            });
+            ui.end_row();
+
+            ui.label("Überrascht").on_hover_text(
+                "Ohne Probe ist die ganze Gruppe überrascht, mit Probe nur wer die \
+                Wahrnehmungsprobe nicht schafft",
+            );
+            ui.add_enabled(
+                self.ambush != Ambush::None,
+                egui::Checkbox::new(&mut self.notice_roll, "Wahrnehmungsprobe"),
+            );
+            ui.end_row();
+        });
+
+        ui.separator();
+        self.environment.draw(ui);
+
+        ui.separator();
+        self.objectives.draw(ui);
+
+        action
+    }
+}
+
+/// Where the fight takes place, affects both groups alike
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(default)]
+pub struct Environment {
+    pub(crate) lighting: Lighting,
+    /// moving takes the whole action and nobody can step back
+    pub(crate) difficult_ground: bool,
+    /// only one attacker gets a gang up bonus and there is no room for a Rundumschlag
+    pub(crate) narrow: bool,
+    pub(crate) rain: bool,
+}
+
+impl Environment {
+    /// penalty to melee and ranged attacks
+    pub fn lighting_penalty(self) -> u8 {
+        self.lighting.penalty()
+    }
+
+    /// additional penalty to ranged attacks
+    pub fn ranged_penalty(self) -> u8 {
+        u8::from(self.rain)
+    }
+
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Umgebung");
+        grid.show(ui, |ui| {
+            ui.label("Licht");
+            ui.horizontal(|ui| {
+                for lighting in Lighting::iter() {
+                    ui.selectable_value(&mut self.lighting, lighting, lighting.as_str())
+                        .on_hover_text(lighting.description());
+                }
+            });
+            ui.end_row();
+
+            ui.label("Gelände");
+            ui.checkbox(&mut self.difficult_ground, "Schwieriger Boden")
+                .on_hover_text(
+                    "Vorrücken kostet die ganze Aktion, zurückweichen (z.B. für Erstschlag) ist \
+                    nicht möglich.",
+                );
+            ui.end_row();
+
+            ui.label("");
+            ui.checkbox(&mut self.narrow, "Enger Gang")
+                .on_hover_text("Höchstens +1 durch Überzahl und kein Platz für Rundumschlag.");
+            ui.end_row();
+
+            ui.label("Wetter");
+            ui.checkbox(&mut self.rain, "Regen")
+                .on_hover_text("-1 auf Fernkampf- und Wurfangriffe.");
+            ui.end_row();
+        });
+    }
+}
+
+/// Goals of the left group besides winning the fight, each one is evaluated on its own
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(default)]
+pub struct Objectives {
+    /// the leaders of the right group have to fall
+    pub(crate) kill_leader: bool,
+    /// the left group has to stand until the end of this round, 0 means no such objective
+    pub(crate) survive_rounds: u8,
+    /// share of the right group in percent that has to fall, 0 means no such objective
+    pub(crate) defeat_percent: u8,
+}
+
+impl Objectives {
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        let grid = widgets::create_grid("Ziele");
+        grid.show(ui, |ui| {
+            ui.label("Schützlinge").on_hover_text(
+                "Schützlinge werden in der Taktik markiert, die Auswertung zeigt dann, wie oft \
+                alle überleben.",
+            );
+            ui.label("über die Taktik");
+            ui.end_row();
+
+            ui.label("Anführer");
+            ui.checkbox(&mut self.kill_leader, "Anführer besiegen")
+                .on_hover_text(
+                    "Alle Gegner mit Anführer-Edges müssen fallen, ohne solche nur der erste \
+                    Gegner.",
+                );
+            ui.end_row();
+
+            ui.label("Runden überleben").on_hover_text(
+                "Die linke Gruppe muss bis zum Ende dieser Runde stehen, 0: kein Ziel",
+            );
+            ui.add(egui::Slider::new(&mut self.survive_rounds, 0..=20));
+            ui.end_row();
+
+            ui.label("Gegner besiegen")
+                .on_hover_text("Anteil der rechten Gruppe, der fallen muss, 0: kein Ziel");
+            ui.add(
+                egui::Slider::new(&mut self.defeat_percent, 0..=100)
+                    .step_by(10.0)
+                    .suffix("%"),
+            );
+            ui.end_row();
+        });
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum_macros::EnumIter,
+)]
+pub enum Lighting {
+    #[default]
+    Bright,
+    Dim,
+    Dark,
+}
+
+impl Lighting {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Bright => "Hell",
+            Self::Dim => "Dämmerung",
+            Self::Dark => "Dunkel",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Bright => "Keine Abzüge.",
+            Self::Dim => "-2 auf alle Angriffe.",
+            Self::Dark => "-4 auf alle Angriffe.",
+        }
+    }
+
+    fn penalty(self) -> u8 {
+        match self {
+            Self::Bright => 0,
+            Self::Dim => 2,
+            Self::Dark => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioAction {
+    Save,
+    Load,
+}
+
+/// The group that lies in ambush
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum_macros::EnumIter,
+)]
+pub enum Ambush {
+    #[default]
+    None,
+    Left,
+    Right,
+}
+
+impl Ambush {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "Keiner",
+            Self::Left => "Links",
+            Self::Right => "Rechts",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::None => "Beide Gruppen ziehen ab der ersten Runde Karten.",
+            Self::Left => {
+                "Überraschte der rechten Gruppe ziehen in der ersten Runde keine Karte, die \
+                linke Gruppe erhält gegen sie +4 auf Angriff und Schaden."
+            }
+            Self::Right => {
+                "Überraschte der linken Gruppe ziehen in der ersten Runde keine Karte, die \
+                rechte Gruppe erhält gegen sie +4 auf Angriff und Schaden."
+            }
+        }
+    }
+}
@@ -5,6 +5,9 @@ use crate::{
 
 pub trait DrawInfo<Value> {
     fn as_str(&self) -> &'static str;
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
     fn mod_dec(&self, selection: app::CharSelection) -> CharModification;
     fn mod_inc(&self, selection: app::CharSelection) -> CharModification;
     fn mod_set(&self, selection: app::CharSelection, value: Value) -> CharModification;
@@ -23,7 +26,10 @@ pub trait ValueSelector: Sized + Copy + Eq {
         sim: &mut Simulator,
         ui: &mut egui::Ui,
     ) {
-        ui.label(info.as_str());
+        let label = ui.label(info.as_str());
+        if let Some(description) = info.description() {
+            label.on_hover_text(description);
+        }
 
         ui.horizontal(|ui| {
             for val in Self::possible_values() {
@@ -83,7 +89,10 @@ pub trait ValueSlider: Sized {
         sim: &mut Simulator,
         ui: &mut egui::Ui,
     ) {
-        ui.label(info.as_str());
+        let label = ui.label(info.as_str());
+        if let Some(description) = info.description() {
+            label.on_hover_text(description);
+        }
 
         let slider = egui::Slider::new(self.inner_mut(), Self::min()..=Self::max());
         ui.add(slider);
@@ -125,6 +134,12 @@ impl<const MIN: i8, const MAX: i8> From<IntStat<MIN, MAX>> for i8 {
 )]
 pub struct BoolStat(bool);
 
+impl From<bool> for BoolStat {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
 impl BoolStat {
     pub fn decrement(&mut self) {
         self.0 = false;
@@ -154,6 +169,9 @@ impl BoolStat {
         ui: &mut egui::Ui,
     ) {
         ui.checkbox(&mut self.0, info.as_str()).on_hover_ui(|ui| {
+            if let Some(description) = info.description() {
+                ui.label(description);
+            }
             ui.horizontal(|ui| {
                 sim.gradient(info.mod_set(selection, self.toggled()))
                     .draw(ui);
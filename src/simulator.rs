@@ -16,7 +16,10 @@ use fight_report::FightReport;
 
 use crate::app::character::Character;
 use crate::app::gradient::Gradient;
-use crate::app::group::CharIndex;
+use crate::app::group::{CharIndex, Group, GroupBennies};
+use crate::app::rules::RuleSet;
+use crate::app::scenario::Scenario;
+use crate::app::widgets::ValueSlider as _;
 use crate::app::{CharSelection, GroupId};
 
 pub type CharModFunc = Box<dyn FnOnce(&mut Character)>;
@@ -53,6 +56,7 @@ pub struct Simulator {
     report_send: mpsc::Sender<(GroupData, FightReport)>,
     report_recv: mpsc::Receiver<(GroupData, FightReport)>,
     group_data: GroupData,
+    variants: Variants,
     progress: ProgressTracker,
 }
 
@@ -76,7 +80,9 @@ impl Simulator {
         let group_data = GroupData {
             group_left,
             group_right,
+            ..GroupData::default()
         };
+        let variants = Variants::new(&group_data);
         let report_map = DataMap::default();
         let workers = ThreadPool::with_name("simulator_worker".to_owned(), 4);
         let (report_send, report_recv) = channel();
@@ -86,21 +92,41 @@ impl Simulator {
             report_send,
             report_recv,
             group_data,
+            variants,
             progress: ProgressTracker::new(),
         }
     }
 
-    pub fn update(&mut self, mut group_left: Vec<Character>, mut group_right: Vec<Character>) {
+    pub fn update(
+        &mut self,
+        group_left: Group,
+        group_right: Group,
+        rules: RuleSet,
+        scenario: Scenario,
+    ) {
+        let (mut group_left, bennies_left, shared_initiative_left) = group_left.into_parts();
+        let (mut group_right, bennies_right, shared_initiative_right) = group_right.into_parts();
         for c in &mut group_left {
             c.name.clear();
         }
         for c in &mut group_right {
             c.name.clear();
         }
-        self.group_data = GroupData {
+        let group_data = GroupData {
             group_left,
             group_right,
+            bennies_left,
+            bennies_right,
+            shared_initiative_left,
+            shared_initiative_right,
+            rules,
+            scenario,
         };
+        // the variants are only derived again when something changed
+        if group_data != self.group_data {
+            self.variants = Variants::new(&group_data);
+            self.group_data = group_data;
+        }
     }
 
     fn update_report_map(&mut self) {
@@ -118,7 +144,18 @@ impl Simulator {
     }
 
     pub fn report(&mut self) -> FightReport {
-        self.request_report(self.group_data.clone())
+        let mut report = self.request_report(self.group_data.clone());
+        // show how the fight would go if extras were treated like wild cards
+        if let Some(wild_cards) = self.variants.wild_cards.clone() {
+            let prob_win = self.request_report(wild_cards).total();
+            report.set_prob_win_wild_cards(prob_win);
+        }
+        // show how much the arrival of reinforcements shifts the fight
+        if let Some(at_start) = self.variants.at_start.clone() {
+            let prob_win = self.request_report(at_start).total();
+            report.set_prob_win_at_start(prob_win);
+        }
+        report
     }
 
     fn request_report(&mut self, group_data: GroupData) -> FightReport {
@@ -148,6 +185,10 @@ impl Simulator {
         FightReport::NONE
     }
 
+    pub fn rules(&self) -> &RuleSet {
+        &self.group_data.rules
+    }
+
     pub fn gradient(&mut self, modification: CharModification) -> Gradient {
         let mut modified_data = self.group_data.clone();
         modified_data.apply_mod(modification);
@@ -205,13 +246,71 @@ impl ProgressTracker {
     }
 }
 
+/// Groups derived from the current ones for the additional chances in the report
+#[derive(Debug, Default)]
+struct Variants {
+    wild_cards: Option<GroupData>,
+    at_start: Option<GroupData>,
+}
+
+impl Variants {
+    fn new(group_data: &GroupData) -> Self {
+        Self {
+            wild_cards: group_data.all_wild_cards(),
+            at_start: group_data.all_at_start(),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 struct GroupData {
     group_left: Vec<Character>,
     group_right: Vec<Character>,
+    bennies_left: GroupBennies,
+    bennies_right: GroupBennies,
+    shared_initiative_left: bool,
+    shared_initiative_right: bool,
+    rules: RuleSet,
+    scenario: Scenario,
 }
 
 impl GroupData {
+    /// the same groups, but with all extras turned into wild cards
+    ///
+    /// Returns `None` if there are no extras.
+    fn all_wild_cards(&self) -> Option<Self> {
+        let mut data = self.clone();
+        let extras = data
+            .group_left
+            .iter_mut()
+            .chain(&mut data.group_right)
+            .filter(|c| !c.experience.is_wild_card());
+        let mut found_extra = false;
+        for character in extras {
+            character.experience.wild_card.increment();
+            found_extra = true;
+        }
+        found_extra.then_some(data)
+    }
+
+    /// the same groups, but with all reinforcements there from the start
+    ///
+    /// Returns `None` if there are no reinforcements.
+    fn all_at_start(&self) -> Option<Self> {
+        let mut data = self.clone();
+        let reinforcements = data
+            .group_left
+            .iter_mut()
+            .chain(&mut data.group_right)
+            .filter(|c| c.tactics.arrival() > 1);
+        let mut found_reinforcement = false;
+        for character in reinforcements {
+            character.tactics.arrival.set(0);
+            found_reinforcement = true;
+        }
+        found_reinforcement.then_some(data)
+    }
+
     fn apply_mod(&mut self, modification: CharModification) {
         let CharModification {
             group_id,
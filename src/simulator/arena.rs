@@ -1,13 +1,17 @@
 use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
 use std::rc::Rc;
 
-use crate::simulator::fight_report::FightStats;
+use crate::app::character::Aura;
+use crate::app::scenario::{Ambush, Objectives, Scenario};
+use crate::simulator::fight_report::{FightStats, ObjectiveResults};
 use crate::simulator::fighter::DistanceMap;
 
 use super::GroupData;
 use super::cards::CardDeck;
 use super::fight_report::{FightOutcome, FightReport, ReportBuilder};
-use super::fighter::{Fighter, Group};
+use super::fighter::{BennyPool, Fighter, Group};
 
 pub fn simulate_fights(char_data: &GroupData, count_fights: u32, max_rounds: u32) -> FightReport {
     let mut report = ReportBuilder::new();
@@ -33,10 +37,26 @@ type FightResult = Result<(), FightIsOver>;
 
 #[derive(Debug)]
 struct Arena {
+    /// the extras of the group draw one initiative card
+    shared_initiative_left: bool,
+    shared_initiative_right: bool,
     cards: CardDeck,
     stats: Rc<RefCell<FightStats>>,
     group_left: Vec<Rc<RefCell<Fighter>>>,
     group_right: Vec<Rc<RefCell<Fighter>>>,
+    /// fighters of both groups that arrive in a later round
+    reserves: Vec<Rc<RefCell<Fighter>>>,
+    /// protected fighters of the left group, dead ones included
+    wards: Vec<Rc<RefCell<Fighter>>>,
+    /// leaders of the right group for the objectives, dead ones included
+    leaders: Vec<Rc<RefCell<Fighter>>>,
+    /// without any leader on the battlefield there are no auras to spread
+    leadership: bool,
+    count_enemies: usize,
+    objectives: Objectives,
+    round: u32,
+    /// the round in which the last fighter of the left group fell
+    left_defeated_in: Option<u32>,
 }
 
 impl Arena {
@@ -44,7 +64,10 @@ impl Arena {
         let cards = CardDeck::new();
         let stats = Rc::new(RefCell::new(FightStats::new()));
         let distance_map = Rc::new(RefCell::new(DistanceMap::new()));
-        let group_left = group_data
+        let rules = Rc::new(group_data.rules.clone());
+        let pool_left = BennyPool::new(&group_data.bennies_left);
+        let pool_right = BennyPool::new(&group_data.bennies_right);
+        let (group_left, reserves_left): (Vec<_>, Vec<_>) = group_data
             .group_left
             .iter()
             .cloned()
@@ -52,70 +75,372 @@ impl Arena {
                 Fighter::new(
                     char,
                     Group::Left,
+                    Rc::clone(&rules),
+                    group_data.scenario.environment,
+                    pool_left.clone(),
                     Rc::clone(&distance_map),
                     Some(Rc::clone(&stats)),
                 )
             })
             .map(|fighter| Rc::new(RefCell::new(fighter)))
-            .collect();
-        let group_right = group_data
+            .partition(|fighter| fighter.borrow().arrival() <= 1);
+        let (group_right, reserves_right): (Vec<_>, Vec<_>) = group_data
             .group_right
             .iter()
             .cloned()
-            .map(|char| Fighter::new(char, Group::Right, Rc::clone(&distance_map), None))
+            .map(|char| {
+                Fighter::new(
+                    char,
+                    Group::Right,
+                    Rc::clone(&rules),
+                    group_data.scenario.environment,
+                    pool_right.clone(),
+                    Rc::clone(&distance_map),
+                    None,
+                )
+            })
             .map(|fighter| Rc::new(RefCell::new(fighter)))
+            .partition(|fighter| fighter.borrow().arrival() <= 1);
+        let wards = group_left
+            .iter()
+            .chain(&reserves_left)
+            .filter(|fighter| fighter.borrow().is_ward())
+            .map(Rc::clone)
+            .collect();
+        let leadership = [&group_left, &reserves_left, &group_right, &reserves_right]
+            .into_iter()
+            .flatten()
+            .any(|fighter| fighter.borrow().is_leader());
+        let mut leaders: Vec<_> = group_right
+            .iter()
+            .chain(&reserves_right)
+            .filter(|fighter| fighter.borrow().is_leader())
+            .map(Rc::clone)
             .collect();
-        Self {
+        if leaders.is_empty() {
+            // without a leader the first opponent counts as one
+            leaders.extend(group_right.first().or(reserves_right.first()).cloned());
+        }
+        let count_enemies = group_right.len() + reserves_right.len();
+        let arena = Self {
+            shared_initiative_left: group_data.shared_initiative_left,
+            shared_initiative_right: group_data.shared_initiative_right,
             cards,
             stats,
             group_left,
             group_right,
+            reserves: [reserves_left, reserves_right].concat(),
+            wards,
+            leaders,
+            leadership,
+            count_enemies,
+            objectives: group_data.scenario.objectives,
+            round: 0,
+            left_defeated_in: None,
+        };
+        Self::frighten(&arena.group_left, &arena.group_right);
+        Self::frighten(&arena.group_right, &arena.group_left);
+        arena.ambush(&group_data.scenario);
+        arena
+    }
+
+    fn ambush(&self, scenario: &Scenario) {
+        let ambushed = match scenario.ambush {
+            Ambush::None => return,
+            Ambush::Left => &self.group_right,
+            Ambush::Right => &self.group_left,
+        };
+        for fighter in ambushed {
+            fighter.borrow_mut().be_ambushed(scenario.notice_roll);
+        }
+    }
+
+    /// scary creatures shake their opponents before the fight
+    fn frighten(fighters: &[Rc<RefCell<Fighter>>], opponents: &[Rc<RefCell<Fighter>>]) {
+        for fighter in fighters {
+            let Some(penalty) = fighter.borrow().fear() else {
+                continue;
+            };
+            for opponent in opponents {
+                opponent.borrow_mut().resist_fear(penalty);
+            }
         }
     }
 
     fn do_fighter_action(&mut self, fighter: &Rc<RefCell<Fighter>>) -> FightResult {
         let mut fighter = fighter.borrow_mut();
-        let opponents = match fighter.group() {
-            Group::Left => &mut self.group_right,
-            Group::Right => &mut self.group_left,
+        let (allies, opponents, other_group) = match fighter.group() {
+            Group::Left => (&self.group_left, &self.group_right, Group::Right),
+            Group::Right => (&self.group_right, &self.group_left, Group::Left),
         };
         if opponents.is_empty() {
+            // wait for the reinforcements of the other group
+            if self.in_reserve(other_group) {
+                return Ok(());
+            }
             return Err(FightIsOver);
         }
 
-        fighter.action(opponents);
+        fighter.action(opponents, allies);
 
         Ok(())
     }
 
+    fn in_reserve(&self, group: Group) -> bool {
+        self.reserves
+            .iter()
+            .any(|fighter| fighter.borrow().group() == group)
+    }
+
+    /// reinforcements join at the back of their group
+    ///
+    /// Fear is resisted when fighters first meet, so arrivals and the fighters already there
+    /// frighten each other.
+    fn reinforce(&mut self) {
+        if self.reserves.is_empty() {
+            return;
+        }
+        let round = self.round;
+        let (arrived, waiting): (Vec<_>, Vec<_>) = self
+            .reserves
+            .drain(..)
+            .partition(|fighter| fighter.borrow().arrival() <= round);
+        self.reserves = waiting;
+        let (arrived_left, arrived_right): (Vec<_>, Vec<_>) = arrived
+            .into_iter()
+            .partition(|fighter| fighter.borrow().group() == Group::Left);
+        Self::frighten(&self.group_left, &arrived_right);
+        Self::frighten(&self.group_right, &arrived_left);
+        self.group_left.extend(arrived_left.iter().cloned());
+        self.group_right.extend(arrived_right.iter().cloned());
+        Self::frighten(&arrived_left, &self.group_right);
+        Self::frighten(&arrived_right, &self.group_left);
+    }
+
     fn round(&mut self) -> FightResult {
+        self.round += 1;
+        self.reinforce();
         self.stats.borrow_mut().add_round();
         self.cards.new_round();
-        self.group_left
-            .iter_mut()
-            .chain(self.group_right.iter_mut())
-            .for_each(|f| f.borrow_mut().new_round(&mut self.cards));
+        Self::deal_cards(
+            &mut self.cards,
+            &self.group_left,
+            self.shared_initiative_left,
+        );
+        Self::deal_cards(
+            &mut self.cards,
+            &self.group_right,
+            self.shared_initiative_right,
+        );
+        if self.leadership {
+            Self::spread_auras(&self.group_left);
+            Self::spread_auras(&self.group_right);
+        }
 
-        let initiative_list = self.initiative();
+        let mut queue: VecDeque<_> = self.initiative().into();
+        let mut on_hold = Vec::new();
 
-        for fighter in initiative_list {
-            // groups don't contain dead fighters, but initiative list is not updated
+        while let Some(fighter) = queue.pop_front() {
+            // groups don't contain dead fighters, but the queue is not updated
             if fighter.borrow().is_dead() {
                 continue;
             }
 
-            self.do_fighter_action(&fighter)?;
-            self.filter_out_dead_fighters();
+            let has_action = fighter.borrow_mut().start_turn();
+            if has_action
+                && fighter.borrow().wants_to_hold()
+                && self.target_acts_later(&fighter, &queue)
+            {
+                on_hold.push(fighter);
+                continue;
+            }
+
+            let too_slow = self.interrupt(&fighter, has_action, &mut on_hold)?;
+            self.take_turn(&fighter)?;
+            // holders that lost the Ges roll act right after
+            for holder in too_slow {
+                self.take_turn(&holder)?;
+            }
+            self.release_holders(&queue, &mut on_hold)?;
+        }
+
+        // held actions that were never used are taken at the end of the round
+        for fighter in on_hold {
+            self.take_turn(&fighter)?;
+        }
+
+        self.group_left
+            .iter()
+            .chain(self.group_right.iter())
+            .for_each(|f| f.borrow_mut().recover_from_surprise());
+
+        Ok(())
+    }
+
+    /// with a shared card, the first extra draws for all extras of the group
+    fn deal_cards(cards: &mut CardDeck, fighters: &[Rc<RefCell<Fighter>>], shared: bool) {
+        let mut extras_card = None;
+        for fighter in fighters {
+            let mut fighter = fighter.borrow_mut();
+            // surprised fighters skip the first card, but their round starts all the same
+            if fighter.is_surprised() {
+                fighter.new_round(None);
+                continue;
+            }
+            let card = if shared && !fighter.wild_card() {
+                *extras_card.get_or_insert_with(|| fighter.draw_card(cards))
+            } else {
+                fighter.draw_card(cards)
+            };
+            fighter.new_round(Some(card));
+        }
+    }
+
+    /// every fighter follows the best aura of the leaders among its allies
+    fn spread_auras(fighters: &[Rc<RefCell<Fighter>>]) {
+        let leaders: Vec<_> = fighters
+            .iter()
+            .map(|fighter| fighter.borrow().leader_aura())
+            .collect();
+        for (index, fighter) in fighters.iter().enumerate() {
+            let aura = leaders
+                .iter()
+                .enumerate()
+                .filter(|&(leader, _aura)| leader != index)
+                .fold(Aura::default(), |sum, (_leader, &aura)| sum.max(aura));
+            fighter.borrow_mut().follow(aura);
+        }
+    }
+
+    /// an inspiring leader unshakes its allies at the start of its turn
+    fn inspire(&self, leader: &Rc<RefCell<Fighter>>) {
+        if !leader.borrow().leader_aura().inspire {
+            return;
+        }
+        let allies = match leader.borrow().group() {
+            Group::Left => &self.group_left,
+            Group::Right => &self.group_right,
+        };
+        let shaken: Vec<_> = allies
+            .iter()
+            .filter(|ally| !Rc::ptr_eq(ally, leader) && ally.borrow().is_shaken())
+            .collect();
+        if shaken.is_empty() || !leader.borrow().inspire() {
+            return;
+        }
+        for ally in shaken {
+            ally.borrow_mut().be_inspired();
         }
+    }
 
+    fn take_turn(&mut self, fighter: &Rc<RefCell<Fighter>>) -> FightResult {
+        if fighter.borrow().is_dead() {
+            return Ok(());
+        }
+        self.inspire(fighter);
+        self.do_fighter_action(fighter)?;
+        self.filter_out_dead_fighters();
         Ok(())
     }
 
+    /// everybody attacks the first fighter of the front rank of the other group
+    fn target_of(&self, fighter: &Rc<RefCell<Fighter>>) -> Option<&Rc<RefCell<Fighter>>> {
+        let opponents = match fighter.borrow().group() {
+            Group::Left => &self.group_right,
+            Group::Right => &self.group_left,
+        };
+        Fighter::target(opponents)
+    }
+
+    fn target_acts_later(
+        &self,
+        fighter: &Rc<RefCell<Fighter>>,
+        queue: &VecDeque<Rc<RefCell<Fighter>>>,
+    ) -> bool {
+        self.target_of(fighter)
+            .is_some_and(|target| queue.iter().any(|other| Rc::ptr_eq(target, other)))
+    }
+
+    /// holders whose target fell or already acted don't wait any longer
+    ///
+    /// The target is picked anew, so a holder keeps waiting for a new target that acts later.
+    fn release_holders(
+        &mut self,
+        queue: &VecDeque<Rc<RefCell<Fighter>>>,
+        on_hold: &mut Vec<Rc<RefCell<Fighter>>>,
+    ) -> FightResult {
+        if on_hold.is_empty() {
+            return Ok(());
+        }
+        let (released, waiting): (Vec<_>, Vec<_>) = on_hold
+            .drain(..)
+            .partition(|holder| !self.target_acts_later(holder, queue));
+        *on_hold = waiting;
+        for holder in released {
+            self.take_turn(&holder)?;
+        }
+        Ok(())
+    }
+
+    /// fighters on hold try to interrupt their target before it acts
+    ///
+    /// Returns the fighters that failed to interrupt, they act right after the target.
+    fn interrupt(
+        &mut self,
+        fighter: &Rc<RefCell<Fighter>>,
+        has_action: bool,
+        on_hold: &mut Vec<Rc<RefCell<Fighter>>>,
+    ) -> Result<Vec<Rc<RefCell<Fighter>>>, FightIsOver> {
+        if on_hold.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (holders, waiting): (Vec<_>, Vec<_>) = on_hold.drain(..).partition(|holder| {
+            self.target_of(holder)
+                .is_some_and(|target| Rc::ptr_eq(target, fighter))
+        });
+        *on_hold = waiting;
+
+        let mut too_slow = Vec::new();
+        for holder in holders {
+            // there is nothing to interrupt if the target lost its action anyway
+            if has_action && Self::wins_opposed_dex_roll(&holder, fighter) {
+                self.take_turn(&holder)?;
+            } else {
+                too_slow.push(holder);
+            }
+        }
+        Ok(too_slow)
+    }
+
+    /// rerolled on a tie
+    fn wins_opposed_dex_roll(fighter: &Rc<RefCell<Fighter>>, other: &Rc<RefCell<Fighter>>) -> bool {
+        loop {
+            let roll = fighter.borrow().dex_roll().ok();
+            let other_roll = other.borrow().dex_roll().ok();
+            match roll.cmp(&other_roll) {
+                Ordering::Less => return false,
+                Ordering::Greater => return true,
+                Ordering::Equal => (),
+            }
+        }
+    }
+
     fn filter_out_dead_fighters(&mut self) {
-        self.group_left
-            .retain(|fighter| !fighter.borrow().is_dead());
-        self.group_right
-            .retain(|fighter| !fighter.borrow().is_dead());
+        let standing = |fighter: &Rc<RefCell<Fighter>>| {
+            let fighter = fighter.borrow();
+            if fighter.is_dead() {
+                // a destroyed weapon counts as a defeat without any damage
+                fighter.disengage();
+                return false;
+            }
+            true
+        };
+        self.group_left.retain(standing);
+        self.group_right.retain(standing);
+        let left_defeated = self.group_left.is_empty() && !self.in_reserve(Group::Left);
+        if left_defeated && self.left_defeated_in.is_none() {
+            self.left_defeated_in = Some(self.round);
+        }
     }
 
     fn initiative(&mut self) -> Vec<Rc<RefCell<Fighter>>> {
@@ -123,6 +448,7 @@ impl Arena {
             .group_left
             .iter()
             .chain(self.group_right.iter())
+            .filter(|fighter| !fighter.borrow().is_surprised())
             .cloned()
             .collect();
         initiative_list.sort_by_key(|a| a.borrow().drawn_card());
@@ -146,32 +472,25 @@ impl Arena {
         if !second.borrow().drawn_card().is_joker() {
             return;
         }
-        let swap = 'dex_roll_loop: loop {
-            match first
-                .borrow()
-                .dex_roll()
-                .ok()
-                .cmp(&second.borrow().dex_roll().ok())
-            {
-                std::cmp::Ordering::Less => break 'dex_roll_loop true,
-                std::cmp::Ordering::Greater => break 'dex_roll_loop false,
-                std::cmp::Ordering::Equal => continue 'dex_roll_loop,
-            }
-        };
-        if swap {
+        if !Self::wins_opposed_dex_roll(first, second) {
             initiative_list.swap(0, 1);
         }
     }
 
     fn finish(mut self) -> FightOutcome {
         self.filter_out_dead_fighters();
-        let left_dead = self.group_left.is_empty();
-        let right_dead = self.group_right.is_empty();
+        let left_dead = self.group_left.is_empty() && !self.in_reserve(Group::Left);
+        let right_dead = self.group_right.is_empty() && !self.in_reserve(Group::Right);
+        let objectives = self.objective_results();
         drop(self.group_left);
         drop(self.group_right);
-        let stats = Rc::into_inner(self.stats)
+        drop(self.reserves);
+        drop(self.wards);
+        drop(self.leaders);
+        let mut stats = Rc::into_inner(self.stats)
             .expect("other Rcs should be gone")
             .into_inner();
+        stats.set_objectives(objectives);
         match (left_dead, right_dead) {
             (true, true) => FightOutcome::Draw(stats),
             (true, false) => FightOutcome::RightWon(stats),
@@ -179,11 +498,45 @@ impl Arena {
             (false, false) => FightOutcome::Draw(stats),
         }
     }
+
+    /// evaluate the objectives of the left group, expects dead fighters to be filtered out
+    fn objective_results(&self) -> ObjectiveResults {
+        let all_dead = |fighters: &[Rc<RefCell<Fighter>>]| {
+            fighters.iter().all(|fighter| fighter.borrow().is_dead())
+        };
+        let wards_survived = (!self.wards.is_empty())
+            .then(|| self.wards.iter().all(|ward| !ward.borrow().is_dead()));
+        let leader_killed = self.objectives.kill_leader.then(|| all_dead(&self.leaders));
+        let survive_rounds = u32::from(self.objectives.survive_rounds);
+        let rounds_survived = (survive_rounds > 0).then(|| {
+            self.left_defeated_in
+                .is_none_or(|round| round > survive_rounds)
+        });
+        let enemies_left = self.group_right.len()
+            + self
+                .reserves
+                .iter()
+                .filter(|fighter| fighter.borrow().group() == Group::Right)
+                .count();
+        let defeated = self.count_enemies - enemies_left;
+        let defeat_percent = usize::from(self.objectives.defeat_percent);
+        let enemies_defeated =
+            (defeat_percent > 0).then(|| 100 * defeated >= defeat_percent * self.count_enemies);
+
+        ObjectiveResults {
+            wards_survived,
+            leader_killed,
+            rounds_survived,
+            enemies_defeated,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::app::character::Character;
+    use crate::app::scenario::{Ambush, Objectives, Scenario};
+    use crate::app::widgets::ValueSlider as _;
 
     use super::*;
 
@@ -196,6 +549,7 @@ mod tests {
         let data = GroupData {
             group_left: vec![character.clone()],
             group_right: vec![character],
+            ..GroupData::default()
         };
 
         let prob: i8 = simulate_fights(&data, count_fights, max_rounds)
@@ -206,4 +560,175 @@ mod tests {
         eprintln!("prob = {prob}");
         assert!((45..=55).contains(&prob), "{prob} is too far away from 50");
     }
+
+    #[test]
+    fn test_holders_wait_until_the_target_acted() {
+        let mut holder = Character::default();
+        holder.tactics.hold.increment();
+        let data = GroupData {
+            group_left: vec![holder],
+            group_right: vec![Character::default()],
+            ..GroupData::default()
+        };
+        let mut arena = Arena::new(&data);
+        let holder = Rc::clone(&arena.group_left[0]);
+        let target = Rc::clone(&arena.group_right[0]);
+        assert!(holder.borrow().wants_to_hold());
+
+        let mut on_hold = vec![holder];
+        let queue = VecDeque::from([target]);
+        assert!(arena.release_holders(&queue, &mut on_hold).is_ok());
+        assert_eq!(on_hold.len(), 1);
+
+        // the held action is not lost once the target acted
+        let _ = arena.release_holders(&VecDeque::new(), &mut on_hold);
+        assert!(on_hold.is_empty());
+    }
+
+    #[test]
+    fn test_extras_share_initiative_card() {
+        let wild_card = Character::default();
+        let mut extra = wild_card.clone();
+        extra.experience.wild_card.decrement();
+        let data = GroupData {
+            group_left: vec![extra.clone(), wild_card, extra.clone(), extra],
+            shared_initiative_left: true,
+            ..GroupData::default()
+        };
+        let mut arena = Arena::new(&data);
+
+        for _ in 0..20 {
+            arena.cards.new_round();
+            Arena::deal_cards(&mut arena.cards, &arena.group_left, true);
+            let card = arena.group_left[0].borrow().drawn_card();
+            assert_eq!(arena.group_left[2].borrow().drawn_card(), card);
+            assert_eq!(arena.group_left[3].borrow().drawn_card(), card);
+        }
+    }
+
+    #[test]
+    fn test_ambushed_fighters_skip_the_first_card() {
+        let data = GroupData {
+            group_left: vec![Character::default()],
+            group_right: vec![Character::default()],
+            scenario: Scenario {
+                ambush: Ambush::Left,
+                notice_roll: false,
+                ..Scenario::default()
+            },
+            ..GroupData::default()
+        };
+        let mut arena = Arena::new(&data);
+        let ambusher = Rc::clone(&arena.group_left[0]);
+        assert!(!ambusher.borrow().is_surprised());
+        assert!(arena.group_right[0].borrow().is_surprised());
+
+        arena.cards.new_round();
+        Arena::deal_cards(&mut arena.cards, &arena.group_left, false);
+        Arena::deal_cards(&mut arena.cards, &arena.group_right, false);
+        let initiative = arena.initiative();
+        assert_eq!(initiative.len(), 1);
+        assert!(Rc::ptr_eq(&initiative[0], &ambusher));
+
+        arena.group_right[0].borrow_mut().recover_from_surprise();
+        arena.cards.new_round();
+        Arena::deal_cards(&mut arena.cards, &arena.group_left, false);
+        Arena::deal_cards(&mut arena.cards, &arena.group_right, false);
+        assert_eq!(arena.initiative().len(), 2);
+    }
+
+    #[test]
+    fn test_reinforcement_joins_at_arrival() {
+        let character = Character::default();
+        let mut reinforcement = character.clone();
+        reinforcement.tactics.arrival.set(3);
+        let data = GroupData {
+            group_left: vec![reinforcement, character.clone()],
+            group_right: vec![character],
+            ..GroupData::default()
+        };
+        let mut arena = Arena::new(&data);
+        assert_eq!(arena.group_left.len(), 1);
+        assert!(arena.in_reserve(Group::Left));
+        assert!(!arena.in_reserve(Group::Right));
+
+        arena.round = 2;
+        arena.reinforce();
+        assert_eq!(arena.group_left.len(), 1);
+
+        arena.round = 3;
+        arena.reinforce();
+        assert!(!arena.in_reserve(Group::Left));
+        // the reinforcement joins at the back
+        assert_eq!(arena.group_left.len(), 2);
+        assert_eq!(arena.group_left[1].borrow().arrival(), 3);
+    }
+
+    #[test]
+    fn test_objectives_of_a_single_fight() {
+        let mut ward = Character::default();
+        ward.tactics.ward.increment();
+        let data = GroupData {
+            group_left: vec![ward],
+            group_right: vec![Character::default(), Character::default()],
+            scenario: Scenario {
+                objectives: Objectives {
+                    kill_leader: true,
+                    survive_rounds: 2,
+                    defeat_percent: 50,
+                },
+                ..Scenario::default()
+            },
+            ..GroupData::default()
+        };
+        let mut arena = Arena::new(&data);
+
+        arena.left_defeated_in = Some(2);
+        let results = arena.objective_results();
+        assert_eq!(results.wards_survived, Some(true));
+        assert_eq!(results.leader_killed, Some(false));
+        assert_eq!(results.rounds_survived, Some(false));
+        assert_eq!(results.enemies_defeated, Some(false));
+
+        // the first opponent leads a group without leaders
+        arena.group_right[0].borrow_mut().kill_for_test();
+        arena.filter_out_dead_fighters();
+        arena.left_defeated_in = Some(3);
+        let results = arena.objective_results();
+        assert_eq!(results.leader_killed, Some(true));
+        assert_eq!(results.rounds_survived, Some(true));
+        assert_eq!(results.enemies_defeated, Some(true));
+    }
+
+    #[test]
+    fn test_rounds_survived_counts_the_round_of_defeat() {
+        let mut doomed = Character::default();
+        doomed.passive_modifiers.life.set(-40);
+        let survive_once = |group_left, group_right| GroupData {
+            group_left,
+            group_right,
+            scenario: Scenario {
+                objectives: Objectives {
+                    survive_rounds: 1,
+                    ..Objectives::default()
+                },
+                ..Scenario::default()
+            },
+            ..GroupData::default()
+        };
+
+        // the left group falls in the first round, the fight only ends in the next one
+        let data = survive_once(vec![doomed.clone()], vec![Character::default()]);
+        let mut arena = Arena::new(&data);
+        while arena.round().is_ok() {}
+        assert_eq!(arena.round, 2);
+        assert_eq!(arena.left_defeated_in, Some(1));
+        assert_eq!(arena.objective_results().rounds_survived, Some(false));
+
+        let data = survive_once(vec![Character::default()], vec![doomed]);
+        let mut arena = Arena::new(&data);
+        while arena.round().is_ok() {}
+        assert_eq!(arena.left_defeated_in, None);
+        assert_eq!(arena.objective_results().rounds_survived, Some(true));
+    }
 }
@@ -115,6 +115,12 @@ pub enum Suit {
     Joker = 15,
 }
 
+impl From<Suit> for u8 {
+    fn from(suit: Suit) -> Self {
+        suit as u8
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
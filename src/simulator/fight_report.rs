@@ -1,5 +1,6 @@
 use egui::{Button, Color32};
 
+use crate::app::character::NaturalAttacks;
 use crate::app::{self, gradient::Total};
 
 #[derive(Debug, Default)]
@@ -15,6 +16,16 @@ pub struct ReportBuilder {
     accumulated_hits_received: u32,
     accumulated_damaging_hits_received: u32,
     accumulated_damage_received: u32,
+    accumulated_healing: u32,
+    accumulated_damage_over_time_dealt: u32,
+    accumulated_damage_over_time_received: u32,
+    /// only counted for won fights
+    accumulated_consumables_used: u32,
+    accumulated_natural_attacks: [NaturalAttackStats; NaturalAttacks::COUNT],
+    wards_survived: Successes,
+    leader_killed: Successes,
+    rounds_survived: Successes,
+    enemies_defeated: Successes,
 }
 
 impl ReportBuilder {
@@ -27,6 +38,7 @@ impl ReportBuilder {
         let stats = match outcome {
             FightOutcome::LeftWon(stats) => {
                 self.count_wins += 1;
+                self.accumulated_consumables_used += stats.consumables_used;
                 stats
             }
             FightOutcome::RightWon(stats) => {
@@ -46,6 +58,20 @@ impl ReportBuilder {
         self.accumulated_hits_received += stats.hits_received;
         self.accumulated_damaging_hits_received += stats.damaging_hits_received;
         self.accumulated_damage_received += stats.damage_received;
+        self.accumulated_healing += stats.healing;
+        self.accumulated_damage_over_time_dealt += stats.damage_over_time_dealt;
+        self.accumulated_damage_over_time_received += stats.damage_over_time_received;
+        self.wards_survived.add(stats.objectives.wards_survived);
+        self.leader_killed.add(stats.objectives.leader_killed);
+        self.rounds_survived.add(stats.objectives.rounds_survived);
+        self.enemies_defeated.add(stats.objectives.enemies_defeated);
+        for (accumulated, natural) in self
+            .accumulated_natural_attacks
+            .iter_mut()
+            .zip(stats.natural_attacks)
+        {
+            accumulated.add(natural);
+        }
     }
 
     pub fn build(self) -> FightReport {
@@ -89,9 +115,39 @@ impl ReportBuilder {
             .accumulated_damage_received
             .checked_div(self.accumulated_damaging_hits_received)
             .unwrap_or(0);
+        let avg_consumables_per_win = self
+            .accumulated_consumables_used
+            .checked_div(self.count_wins)
+            .unwrap_or(0);
+        let avg_healing = self
+            .accumulated_healing
+            .checked_div(self.count_fights)
+            .unwrap_or(0);
+        let avg_dot_dealt = self
+            .accumulated_damage_over_time_dealt
+            .checked_div(self.count_fights)
+            .unwrap_or(0);
+        let avg_dot_received = self
+            .accumulated_damage_over_time_received
+            .checked_div(self.count_fights)
+            .unwrap_or(0);
+        let avg_natural_attacks = self.accumulated_natural_attacks.map(|natural| {
+            // attacks that were never made are not shown
+            (natural.attacks > 0).then(|| {
+                let avg_hits = natural.hits / self.count_fights;
+                let avg_damage = natural.damage.checked_div(natural.hits).unwrap_or(0);
+                (avg_hits.into(), avg_damage.into())
+            })
+        });
 
         FightReport {
             prob_win,
+            prob_win_wild_cards: None,
+            prob_win_at_start: None,
+            prob_wards_survive: self.wards_survived.prob(),
+            prob_leader_killed: self.leader_killed.prob(),
+            prob_rounds_survived: self.rounds_survived.prob(),
+            prob_enemies_defeated: self.enemies_defeated.prob(),
             prob_draw,
             avg_rounds: avg_rounds.into(),
             avg_hits_dealt: avg_hits_dealt.into(),
@@ -100,10 +156,38 @@ impl ReportBuilder {
             avg_hits_received: avg_hits_received.into(),
             avg_dmg_hits_received: avg_dmg_hits_received.into(),
             avg_damage_received: avg_damage_received.into(),
+            avg_healing: avg_healing.into(),
+            avg_consumables_per_win: avg_consumables_per_win.into(),
+            avg_dot_dealt: avg_dot_dealt.into(),
+            avg_dot_received: avg_dot_received.into(),
+            avg_natural_attacks,
         }
     }
 }
 
+/// how often an objective was reached in the fights that had it
+#[derive(Debug, Default, Clone, Copy)]
+struct Successes {
+    fights: u32,
+    successes: u32,
+}
+
+impl Successes {
+    fn add(&mut self, success: Option<bool>) {
+        let Some(success) = success else {
+            return;
+        };
+        self.fights += 1;
+        self.successes += u32::from(success);
+    }
+
+    fn prob(self) -> Option<Total> {
+        let prob = (100 * self.successes).checked_div(self.fights)?;
+        let prob: i8 = prob.try_into().unwrap();
+        Some(prob.try_into().unwrap())
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 struct Stat(Option<u32>);
 
@@ -139,6 +223,16 @@ impl Stat {
 #[derive(Debug, Default, Clone)]
 pub struct FightReport {
     prob_win: Total,
+    /// win chance if all extras were wild cards, only set if there are extras
+    prob_win_wild_cards: Option<Total>,
+    /// win chance if all reinforcements were there from the start, only set if there are any
+    prob_win_at_start: Option<Total>,
+    /// chance that all wards of the left group survive, only set if there are any
+    prob_wards_survive: Option<Total>,
+    /// chances to reach the objectives of the scenario, only set for chosen objectives
+    prob_leader_killed: Option<Total>,
+    prob_rounds_survived: Option<Total>,
+    prob_enemies_defeated: Option<Total>,
     prob_draw: Total,
     avg_rounds: Stat,
     avg_hits_dealt: Stat,
@@ -147,11 +241,24 @@ pub struct FightReport {
     avg_hits_received: Stat,
     avg_dmg_hits_received: Stat,
     avg_damage_received: Stat,
+    avg_healing: Stat,
+    avg_consumables_per_win: Stat,
+    /// damage by poison and bleeding per fight
+    avg_dot_dealt: Stat,
+    avg_dot_received: Stat,
+    /// hits per fight and damage per hit of each natural attack, only set for used attacks
+    avg_natural_attacks: [Option<(Stat, Stat)>; NaturalAttacks::COUNT],
 }
 
 impl FightReport {
     pub const NONE: Self = Self {
         prob_win: Total::NONE,
+        prob_win_wild_cards: None,
+        prob_win_at_start: None,
+        prob_wards_survive: None,
+        prob_leader_killed: None,
+        prob_rounds_survived: None,
+        prob_enemies_defeated: None,
         prob_draw: Total::NONE,
         avg_rounds: Stat::NONE,
         avg_hits_dealt: Stat::NONE,
@@ -160,9 +267,20 @@ impl FightReport {
         avg_hits_received: Stat::NONE,
         avg_dmg_hits_received: Stat::NONE,
         avg_damage_received: Stat::NONE,
+        avg_healing: Stat::NONE,
+        avg_consumables_per_win: Stat::NONE,
+        avg_dot_dealt: Stat::NONE,
+        avg_dot_received: Stat::NONE,
+        avg_natural_attacks: [None; NaturalAttacks::COUNT],
     };
     pub const ZERO: Self = Self {
         prob_win: Total::ZERO,
+        prob_win_wild_cards: None,
+        prob_win_at_start: None,
+        prob_wards_survive: None,
+        prob_leader_killed: None,
+        prob_rounds_survived: None,
+        prob_enemies_defeated: None,
         prob_draw: Total::ZERO,
         avg_rounds: Stat::ZERO,
         avg_hits_dealt: Stat::ZERO,
@@ -171,6 +289,11 @@ impl FightReport {
         avg_hits_received: Stat::ZERO,
         avg_dmg_hits_received: Stat::ZERO,
         avg_damage_received: Stat::ZERO,
+        avg_healing: Stat::ZERO,
+        avg_consumables_per_win: Stat::ZERO,
+        avg_dot_dealt: Stat::ZERO,
+        avg_dot_received: Stat::ZERO,
+        avg_natural_attacks: [None; NaturalAttacks::COUNT],
     };
 
     const STAT_SIZE: [f32; 2] = [30.0, 20.0];
@@ -179,6 +302,72 @@ impl FightReport {
         self.prob_win
     }
 
+    pub fn set_prob_win_wild_cards(&mut self, prob_win: Total) {
+        self.prob_win_wild_cards = Some(prob_win);
+    }
+
+    pub fn set_prob_win_at_start(&mut self, prob_win: Total) {
+        self.prob_win_at_start = Some(prob_win);
+    }
+
+    fn draw_chances(&self, highlight_color: egui::Color32, ui: &mut egui::Ui) {
+        ui.visuals_mut().override_text_color = Some(highlight_color);
+        ui.label("Gewinnchance");
+        self.prob_win.draw(Self::STAT_SIZE, ui);
+        ui.visuals_mut().override_text_color = None;
+        ui.end_row();
+
+        let optional_probs = [
+            (
+                "Gewinnch. Wildcards",
+                "Gewinnchance, wenn alle Statisten wie Wildcards kämpfen würden",
+                self.prob_win_wild_cards,
+            ),
+            (
+                "Gewinnch. sofort",
+                "Gewinnchance, wenn alle Verstärkungen von Anfang an dabei wären",
+                self.prob_win_at_start,
+            ),
+            (
+                "Schützlinge überleben",
+                "Chance, dass alle Schützlinge der linken Gruppe überleben",
+                self.prob_wards_survive,
+            ),
+            (
+                "Ziel: Anführer",
+                "Chance, dass alle Anführer der rechten Gruppe fallen",
+                self.prob_leader_killed,
+            ),
+            (
+                "Ziel: Runden",
+                "Chance, dass die linke Gruppe die Runden des Szenarios übersteht",
+                self.prob_rounds_survived,
+            ),
+            (
+                "Ziel: Gegner",
+                "Chance, dass der Anteil der Gegner aus dem Szenario fällt",
+                self.prob_enemies_defeated,
+            ),
+        ];
+        for (label, description, prob) in optional_probs {
+            // only shown if there are extras, reinforcements, wards or objectives
+            let Some(prob) = prob else {
+                continue;
+            };
+            ui.label(label).on_hover_text(description);
+            prob.draw(Self::STAT_SIZE, ui);
+            ui.end_row();
+        }
+
+        ui.label("Chance Unentsch.");
+        self.prob_draw.draw(Self::STAT_SIZE, ui);
+        ui.end_row();
+
+        ui.label("Ø Runden / Kampf");
+        self.avg_rounds.draw(Self::STAT_SIZE, ui);
+        ui.end_row();
+    }
+
     pub fn draw(&self, ui: &mut egui::Ui) {
         const NUM_TABLES: f32 = 3.0;
         const TABLE_WIDTH: f32 = app::EDITOR_WIDTH / (NUM_TABLES * 1.1);
@@ -205,19 +394,7 @@ impl FightReport {
 
             ui.horizontal(|ui| {
                 draw_table(ui, "results1", |ui| {
-                    ui.visuals_mut().override_text_color = Some(highlight_color);
-                    ui.label("Gewinnchance");
-                    self.prob_win.draw(Self::STAT_SIZE, ui);
-                    ui.visuals_mut().override_text_color = None;
-                    ui.end_row();
-
-                    ui.label("Chance Unentsch.");
-                    self.prob_draw.draw(Self::STAT_SIZE, ui);
-                    ui.end_row();
-
-                    ui.label("Ø Runden / Kampf");
-                    self.avg_rounds.draw(Self::STAT_SIZE, ui);
-                    ui.end_row();
+                    self.draw_chances(highlight_color, ui);
                 });
 
                 draw_table(ui, "results2", |ui| {
@@ -232,6 +409,35 @@ impl FightReport {
                     ui.label("Ø Schaden / Schlag");
                     self.avg_damage_dealt.draw(Self::STAT_SIZE, ui);
                     ui.end_row();
+
+                    ui.label("Ø Heilung / Kampf");
+                    self.avg_healing.draw(Self::STAT_SIZE, ui);
+                    ui.end_row();
+
+                    ui.label("Ø Verbrauchsg. / Sieg")
+                        .on_hover_text("Verbrauchte Tränke, Elixiere und Wurfmesser pro Sieg");
+                    self.avg_consumables_per_win.draw(Self::STAT_SIZE, ui);
+                    ui.end_row();
+
+                    ui.label("Ø Gift/Blutung / Kampf")
+                        .on_hover_text("Ø Schaden pro Kampf durch Gift und Blutungen");
+                    self.avg_dot_dealt.draw(Self::STAT_SIZE, ui);
+                    ui.end_row();
+
+                    for (index, natural) in self.avg_natural_attacks.iter().enumerate() {
+                        let Some((avg_hits, avg_damage)) = natural else {
+                            continue;
+                        };
+                        let number = index + 1;
+                        ui.label(format!("Ø Treffer Angriff {number}"));
+                        avg_hits.draw(Self::STAT_SIZE, ui);
+                        ui.end_row();
+
+                        ui.label(format!("Ø Schaden Angriff {number}"))
+                            .on_hover_text("Ø Schaden pro Treffer des natürlichen Angriffs");
+                        avg_damage.draw(Self::STAT_SIZE, ui);
+                        ui.end_row();
+                    }
                 });
 
                 draw_table(ui, "results3", |ui| {
@@ -246,6 +452,11 @@ impl FightReport {
                     ui.label("Ø erh. Schaden / Schlag");
                     self.avg_damage_received.draw(Self::STAT_SIZE, ui);
                     ui.end_row();
+
+                    ui.label("Ø erh. Gift/Blutung")
+                        .on_hover_text("Ø erhaltener Schaden pro Kampf durch Gift und Blutungen");
+                    self.avg_dot_received.draw(Self::STAT_SIZE, ui);
+                    ui.end_row();
                 });
             });
         });
@@ -261,6 +472,12 @@ pub struct FightStats {
     hits_received: u32,
     damaging_hits_received: u32,
     damage_received: u32,
+    healing: u32,
+    consumables_used: u32,
+    damage_over_time_dealt: u32,
+    damage_over_time_received: u32,
+    natural_attacks: [NaturalAttackStats; NaturalAttacks::COUNT],
+    objectives: ObjectiveResults,
 }
 
 impl FightStats {
@@ -289,6 +506,66 @@ impl FightStats {
         self.damaging_hits_received += 1;
         self.damage_received += u32::from(damage);
     }
+
+    pub fn add_healing(&mut self, healing: u8) {
+        self.healing += u32::from(healing);
+    }
+
+    pub fn add_consumable_used(&mut self) {
+        self.consumables_used += 1;
+    }
+
+    pub fn add_damage_over_time_dealt(&mut self, damage: u8) {
+        self.damage_over_time_dealt += u32::from(damage);
+    }
+
+    pub fn add_damage_over_time_received(&mut self, damage: u8) {
+        self.damage_over_time_received += u32::from(damage);
+    }
+
+    pub fn set_objectives(&mut self, objectives: ObjectiveResults) {
+        self.objectives = objectives;
+    }
+
+    /// `damage` is only counted for hits
+    #[cfg(test)]
+    pub fn natural_attacks_made(&self, index: usize) -> u32 {
+        self.natural_attacks[index].attacks
+    }
+
+    pub fn add_natural_attack(&mut self, index: usize, hit: bool, damage: u8) {
+        let stats = &mut self.natural_attacks[index];
+        stats.attacks += 1;
+        if hit {
+            stats.hits += 1;
+            stats.damage += u32::from(damage);
+        }
+    }
+}
+
+/// stats of a single natural attack
+#[derive(Debug, Default, Clone, Copy)]
+struct NaturalAttackStats {
+    attacks: u32,
+    hits: u32,
+    damage: u32,
+}
+
+impl NaturalAttackStats {
+    fn add(&mut self, other: Self) {
+        self.attacks += other.attacks;
+        self.hits += other.hits;
+        self.damage += other.damage;
+    }
+}
+
+/// objectives of the left group reached in a single fight, `None` if it had no such objective
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ObjectiveResults {
+    pub wards_survived: Option<bool>,
+    pub leader_killed: Option<bool>,
+    pub rounds_survived: Option<bool>,
+    pub enemies_defeated: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
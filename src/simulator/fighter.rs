@@ -1,45 +1,113 @@
-use std::cell::{RefCell, RefMut};
-use std::collections::HashMap;
+mod afflictions;
+mod casting;
+mod consumables;
+mod environment;
+mod formation;
+mod healing;
+mod leadership;
+mod modifiers;
+mod protection;
+mod ranged;
+mod surprise;
+mod wounds;
+
+use std::cell::{Cell, RefCell, RefMut};
 use std::rc::Rc;
 
-use crate::app::character::{Character, Edge3, PassiveStats};
+use crate::app::character::Weapon;
+use crate::app::character::{
+    AttackKind, Aura, Capability, Character, EdgeContext, EdgeHooks, EdgeId, EdgeTrigger,
+    Initiative, Modifier, NaturalAttacks, PassiveStats, Trigger,
+};
+use crate::app::group::GroupBennies;
+use crate::app::rules::{Fumble, FumbleTable, RuleSet};
+use crate::app::scenario::Environment;
 use crate::simulator::fight_report::FightStats;
 use crate::simulator::roller::RollError;
 
 use super::{
-    cards::{Card, CardDeck, Suit},
+    cards::{Card, CardDeck},
     roller::{Roll, RollResult, roller},
 };
 
+use afflictions::Afflictions;
+use consumables::Supplies;
+use modifiers::{Duration, Source, Target, TimedModifiers};
+use wounds::{Location, Wounds};
+
 struct NoOpponentLeft;
 type ActionResult<T> = Result<T, NoOpponentLeft>;
 
 #[derive(Debug, Clone, Copy)]
 struct CriticalMiss;
 
+/// what an attack is made with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttackSource {
+    Primary,
+    Secondary,
+    /// index of the natural attack
+    Natural(usize),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Group {
     Left,
     Right,
 }
 
+/// Bennies the wild cards of a group share
+#[derive(Debug, Clone, Default)]
+pub struct BennyPool {
+    shared: Rc<Cell<u8>>,
+    /// the wild cards also have their own bennies
+    individual: bool,
+}
+
+impl BennyPool {
+    pub fn new(bennies: &GroupBennies) -> Self {
+        Self {
+            shared: Rc::new(Cell::new(bennies.shared())),
+            individual: bennies.individual(),
+        }
+    }
+}
+
 #[allow(clippy::struct_excessive_bools, reason = "lots of yes/no state")]
 #[derive(Debug, Clone)]
 pub struct Fighter {
     group: Group,
+    rules: Rc<RuleSet>,
+    environment: Environment,
     fight_stats: Option<Rc<RefCell<FightStats>>>,
     drawn_card: Option<Card>,
     character: Character,
     passive_stats: PassiveStats,
+    /// own bennies
     bennies: u8,
+    pool: BennyPool,
+    max_life: u8,
+    power_points: u8,
+    ammo: u8,
+    /// actions left until the ranged weapon is loaded again
+    reloading: u8,
+    supplies: Supplies,
+    modifiers: TimedModifiers,
+    afflictions: Afflictions,
+    wounds: Wounds,
+    /// bonus by the leaders of our group
+    aura: Aura,
+    /// doesn't act in the first round of an ambush
+    surprised: bool,
     shaken: bool,
-    interrupted: bool,
+    wounded: bool,
+    /// set at the start of the turn, an action can be held to interrupt others
+    has_action: bool,
     fell: bool,
     joker: bool,
     weapon_lost: bool,
-    berserker: bool,
-    riposte_done: bool,
-    erstschlag_done: bool,
+    /// edges that can only be used once per round and were already used
+    counters_done: Vec<EdgeId>,
     attacked_wild: bool,
     distance_map: Rc<RefCell<DistanceMap>>,
     distance_id: u16,
@@ -49,183 +117,438 @@ impl Fighter {
     pub fn new(
         character: Character,
         group: Group,
+        rules: Rc<RuleSet>,
+        environment: Environment,
+        pool: BennyPool,
         distance_map: Rc<RefCell<DistanceMap>>,
         stats: Option<Rc<RefCell<FightStats>>>,
     ) -> Self {
-        let passive_stats = PassiveStats::new(&character);
-        let berserker = character.edges.berserker == Edge3::Improved;
-        let bennies = i8::from(character.bennies.count).try_into().unwrap();
-        let distance_id = distance_map.borrow_mut().register_fighter(group);
-        Self {
+        let passive_stats = PassiveStats::new(&character, &rules);
+        // extras don't get any bennies
+        let bennies = if character.experience.is_wild_card() && pool.individual {
+            i8::from(character.bennies.count).try_into().unwrap()
+        } else {
+            0
+        };
+        let power_points = i8::from(character.arcane.power_points).try_into().unwrap();
+        let supplies = Supplies::new(&character.consumables);
+        let ammo = if character.ranged_weapon.active() {
+            character.ranged_weapon.ammo()
+        } else {
+            0
+        };
+        let distance_id = distance_map.borrow_mut().register_fighter();
+        let mut fighter = Self {
             group,
+            rules,
+            environment,
             fight_stats: stats,
             drawn_card: None,
             character,
+            max_life: passive_stats.life,
             passive_stats,
             bennies,
+            pool,
+            power_points,
+            ammo,
+            reloading: 0,
+            supplies,
+            modifiers: TimedModifiers::default(),
+            afflictions: Afflictions::default(),
+            wounds: Wounds::default(),
+            aura: Aura::default(),
+            surprised: false,
             shaken: false,
-            interrupted: false,
+            wounded: false,
+            has_action: false,
             fell: false,
             joker: false,
             weapon_lost: false,
-            berserker,
-            riposte_done: false,
-            erstschlag_done: false,
+            counters_done: Vec::new(),
             attacked_wild: false,
             distance_map,
             distance_id,
-        }
+        };
+        fighter.apply_edge_triggers(|hooks| hooks.on_fight_start, None);
+        fighter
+    }
+
+    /// a fighter on its own, for testing mechanisms without an arena
+    #[cfg(test)]
+    pub(crate) fn for_test(character: Character, rules: RuleSet) -> Self {
+        Self::new(
+            character,
+            Group::Left,
+            Rc::new(rules),
+            Environment::default(),
+            BennyPool::new(&GroupBennies::default()),
+            Rc::new(RefCell::new(DistanceMap::new())),
+            None,
+        )
+    }
+
+    /// an opponent on the same battlefield, with the same rules
+    #[cfg(test)]
+    pub(crate) fn opponent_for_test(&self, character: Character) -> Rc<RefCell<Self>> {
+        let opponent = Self::new(
+            character,
+            Group::Right,
+            Rc::clone(&self.rules),
+            self.environment,
+            BennyPool::new(&GroupBennies::default()),
+            Rc::clone(&self.distance_map),
+            None,
+        );
+        Rc::new(RefCell::new(opponent))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn kill_for_test(&mut self) {
+        self.passive_stats.life = 0;
     }
 
     pub fn group(&self) -> Group {
         self.group
     }
 
-    fn draw_card(&mut self, cards: &mut CardDeck) {
-        let num_cards = match self.character.edges.kuhler_kopf {
-            Edge3::None => 1,
-            Edge3::Normal => 2,
-            Edge3::Improved => 3,
+    /// our group without ourselves, we are already borrowed while acting
+    fn other_allies<'a>(
+        &self,
+        allies: &'a [Rc<RefCell<Fighter>>],
+    ) -> impl Iterator<Item = &'a Rc<RefCell<Fighter>>> + use<'a> {
+        let this: *const Self = self;
+        allies
+            .iter()
+            .filter(move |ally| !std::ptr::eq(ally.as_ptr(), this))
+    }
+
+    /// round in which the fighter joins the fight
+    pub fn arrival(&self) -> u32 {
+        self.character.tactics.arrival()
+    }
+
+    pub fn draw_card(&self, cards: &mut CardDeck) -> Card {
+        // most chars don't have any edges, so skip building the context
+        let initiative = if self.character.edges.is_empty() {
+            Initiative::default()
+        } else {
+            self.character
+                .edges
+                .initiative(self.edge_context(None, None))
         };
+        let num_cards = 1 + initiative.extra_cards;
 
         let mut card = (0..num_cards).map(|_| cards.draw()).max().unwrap();
 
-        if self.character.edges.schnell.is_set() {
-            while card.suit() < Suit::Seven {
-                card = cards.draw();
-            }
+        while u8::from(card.suit()) < initiative.redraw_below {
+            card = cards.draw();
+        }
+        for _ in 0..self.head_wounds() {
+            card = card.min(cards.draw());
         }
 
-        self.drawn_card = Some(card);
+        card
     }
 
     pub fn drawn_card(&self) -> Card {
         self.drawn_card.unwrap()
     }
 
+    pub fn wild_card(&self) -> bool {
+        self.character.experience.is_wild_card()
+    }
+
+    fn weapon_reach(&self) -> u8 {
+        i8::from(self.character.weapon.reach)
+            .try_into()
+            .unwrap_or(0)
+    }
+
     fn weapon_has_reach(&self) -> bool {
-        i8::from(self.character.weapon.reach) > 0
+        self.weapon_reach() > 0
     }
 
-    pub fn new_round(&mut self, cards: &mut CardDeck) {
-        self.draw_card(cards);
-        self.joker = self.drawn_card.unwrap().is_joker();
-        self.riposte_done = false;
-        self.erstschlag_done = false;
+    fn dual_wielding(&self) -> bool {
+        self.character.weapon.active && self.character.secondary_weapon.active
+    }
+
+    fn has_capability(&self, capability: Capability) -> bool {
+        self.character.edges.grants(capability)
+    }
+
+    /// kampfkünstler and creatures with claws etc. can't lose their weapons
+    fn keeps_weapon(&self) -> bool {
+        self.character.creature.natural_weapons.is_set()
+            || self.character.natural_attacks.any_active()
+            || self.has_capability(Capability::MartialArtist)
+    }
+
+    fn undead(&self) -> bool {
+        self.character.creature.undead.is_set()
+    }
+
+    /// attacks against bigger opponents are easier
+    fn scale_modifier(&self, opponent: &Self) -> i8 {
+        opponent.character.creature.scale() - self.character.creature.scale()
+    }
+
+    pub fn fear(&self) -> Option<u8> {
+        self.character.creature.fear()
+    }
+
+    /// roll Wil against a scary creature at the start of the fight
+    pub fn resist_fear(&mut self, penalty: u8) {
+        if self.undead() {
+            return;
+        }
+        let Ok(mut roll) = roller().roll_attribute(self.character.attributes.wil, self.wild_card())
+        else {
+            self.shaken = true;
+            return;
+        };
+        roll -= penalty;
+        if roll.eval() == RollResult::Fail {
+            self.shaken = true;
+        }
+    }
+
+    /// the situation in which the hooks of our edges are evaluated
+    fn edge_context(&self, opponent: Option<&Self>, attack: Option<AttackKind>) -> EdgeContext {
+        EdgeContext {
+            attack,
+            dual_wielding: self.dual_wielding(),
+            opponent_reach: opponent.map_or(0, Self::weapon_reach),
+            opponent_shaken: opponent.is_some_and(|opponent| opponent.shaken),
+            ..EdgeContext::default()
+        }
+    }
+
+    fn edge_modifier(
+        &self,
+        hook: impl Fn(&EdgeHooks) -> Option<Modifier>,
+        opponent: Option<&Self>,
+        attack: Option<AttackKind>,
+    ) -> i8 {
+        // most chars don't have any edges, so skip building the context
+        if self.character.edges.is_empty() {
+            return 0;
+        }
+        let ctx = self.edge_context(opponent, attack);
+        self.character.edges.modifier(hook, ctx)
+    }
+
+    /// evaluate a trigger hook of our edges and react to the results
+    fn apply_edge_triggers(
+        &mut self,
+        hook: impl Fn(&EdgeHooks) -> Option<Trigger>,
+        mut opponent: Option<&mut Self>,
+    ) {
+        if self.character.edges.is_empty() {
+            return;
+        }
+        let ctx = self.edge_context(opponent.as_deref(), None);
+        let triggers: Vec<_> = self.character.edges.triggers(hook, ctx).collect();
+        for (edge, trigger) in triggers {
+            match trigger {
+                EdgeTrigger::Rage => self.rage(),
+                EdgeTrigger::Counter { once_per_round } => {
+                    if let Some(opponent) = opponent.as_deref_mut() {
+                        self.counter_attack(edge, once_per_round, opponent);
+                    }
+                }
+            }
+        }
+    }
+
+    /// `card` is the drawn initiative card, maybe shared with other extras
+    ///
+    /// Surprised fighters get no card, but everything else at the start of the round happens.
+    pub fn new_round(&mut self, card: Option<Card>) {
+        self.drawn_card = card;
+        self.joker = card.is_some_and(Card::is_joker);
+        self.counters_done.clear();
+        self.modifiers.tick_round();
+        self.suffer_afflictions();
+        let regeneration = self.character.creature.regeneration();
+        if regeneration > 0 && !self.is_dead() {
+            self.receive_healing(regeneration);
+        }
+    }
+
+    /// a berserker keeps raging until the end of the fight
+    fn rage(&mut self) {
+        let source = Source::Rage;
+        if self.modifiers.has(source) {
+            return;
+        }
+        let duration = Duration::Fight;
+        self.modifiers.add(source, Target::Attack, 2, duration);
+        self.modifiers.add(source, Target::Parry, -2, duration);
+        self.modifiers.add(source, Target::Damage, 2, duration);
+        self.modifiers.add(source, Target::Armor, 2, duration);
+    }
+
+    fn raging(&self) -> bool {
+        self.modifiers.has(Source::Rage)
     }
 
     pub fn is_dead(&self) -> bool {
-        let threshold = if self.berserker { 0 } else { 5 };
+        // extras are out at the first wound
+        if self.rules.extras_die_at_first_wound && self.wounded && !self.wild_card() {
+            return true;
+        }
+        let threshold = if self.raging() {
+            0
+        } else {
+            self.rules.death_threshold
+        };
         self.passive_stats.life <= threshold
     }
 
     /// take a step forward, but only toward our target
-    fn step_forward(&mut self, opponents: &[Rc<RefCell<Fighter>>]) -> ActionResult<()> {
+    ///
+    /// Returns `true` if we actually moved.
+    fn step_forward(&mut self, opponents: &[Rc<RefCell<Fighter>>]) -> ActionResult<bool> {
         let mut opponent = self.pick_opponent(opponents)?;
         let mut distance_map = self.distance_map.borrow_mut();
-        let base_contact_to_target = distance_map.base_contact_mut(self, &opponent);
-        if self.weapon_has_reach() || *base_contact_to_target {
+        if distance_map.base_contact(self, &opponent) {
             // don't step forward if not needed
-            return Ok(());
+            return Ok(false);
         }
-        *base_contact_to_target = true;
-        let base_contact = *base_contact_to_target;
+        if self.weapon_has_reach() {
+            // attack from a distance instead
+            distance_map.engage_at_reach(self, &opponent);
+            return Ok(false);
+        }
+        distance_map.set_base_contact(self, &opponent, true);
         drop(distance_map);
-        opponent.trigger_erstschlag(self, base_contact);
-        Ok(())
+        opponent.apply_edge_triggers(|hooks| hooks.on_approach, Some(self));
+        Ok(true)
     }
 
-    /// take a step back from everybody
+    /// take a step back from everybody, adjacent opponents get a free attack
+    ///
+    /// Without the disengage tactic we only withdraw to react to opponents stepping forward, and
+    /// only if nobody gets a free attack. A reach weapon keeps the opponents at a distance, so they
+    /// get no free attack.
     fn step_back(&mut self, opponents: &[Rc<RefCell<Fighter>>]) {
-        if self.character.edges.erstschlag == Edge3::None {
-            // if we don't have erstschlag, don't step back
+        let disengage = self.character.tactics.disengage.is_set();
+        if !disengage && !self.character.edges.has_hook(|hooks| hooks.on_approach) {
+            return;
+        }
+        if self.environment.difficult_ground {
             return;
         }
 
-        let opponents_cant_attack = self.weapon_has_reach()
-            || opponents
+        let adjacent: Vec<_> = if self.weapon_has_reach() {
+            Vec::new()
+        } else {
+            opponents
                 .iter()
                 .filter(|opponent| !opponent.borrow().is_dead())
-                .map(|opponent| opponent.borrow_mut())
-                .map(|mut opponent| {
-                    opponent.unshake_against_step_back();
-                    opponent
+                .filter(|opponent| {
+                    self.distance_map
+                        .borrow()
+                        .base_contact(self, &opponent.borrow())
                 })
-                .all(|opponent| opponent.shaken);
+                .collect()
+        };
+        for opponent in &adjacent {
+            opponent.borrow_mut().unshake_against_step_back();
+        }
+        let attackers: Vec<_> = adjacent
+            .into_iter()
+            .filter(|opponent| !opponent.borrow().shaken)
+            .collect();
 
-        if !opponents_cant_attack {
+        if !attackers.is_empty() && !disengage {
             // don't step back if an opponent could hit us
             return;
         }
+        for attacker in attackers {
+            attacker.borrow_mut().free_attack(self);
+            if self.is_dead() {
+                return;
+            }
+        }
 
-        // step back from all opponents
-        for opponent in opponents
-            .iter()
-            .filter(|opponent| !opponent.borrow().is_dead())
-            .map(|opponent| opponent.borrow_mut())
-        {
-            let mut distance_map = self.distance_map.borrow_mut();
-            let base_contact_to_opponent = distance_map.base_contact_mut(self, &opponent);
-            *base_contact_to_opponent = false;
+        // step back from all opponents, out of their reach as well
+        self.disengage();
+    }
+
+    /// nobody is engaged with us any longer, after stepping back or falling
+    pub fn disengage(&self) {
+        self.distance_map.borrow_mut().withdraw(self);
+    }
+
+    /// returns the attack and damage modifier for a wild attack
+    fn attack_wild(&mut self, attack: AttackKind) -> i8 {
+        if !self.character.passive_modifiers.attack_wild.is_set() {
+            return 0;
+        }
+        let out_of_turn = matches!(attack, AttackKind::Counter | AttackKind::Free);
+        if out_of_turn && !self.has_capability(Capability::WildCounterAttacks) {
+            return 0;
         }
+        self.attacked_wild = true;
+        2
     }
 
     fn attack_with_primary_weapon(&mut self, opponent: &mut Fighter) {
-        let mut dmg_modifier = 0;
-        let (num_rolls, mut attack_modifier) = match self.character.edges.blitzhieb {
-            Edge3::None => (1, 0),
-            Edge3::Normal => (2, -2),
-            Edge3::Improved => (2, 0),
-        };
-        if self.character.passive_modifiers.attack_wild.is_set() {
-            self.attacked_wild = true;
-            attack_modifier += 2;
-            dmg_modifier += 2;
-        }
-        if self.character.secondary_weapon.active
-            && !self.character.edges.beidhandiger_kampf.is_set()
-        {
+        let attack = AttackKind::Primary;
+        let ctx = self.edge_context(Some(opponent), Some(attack));
+        let num_rolls = 1 + usize::from(self.character.edges.attack_dice(ctx));
+        let dmg_modifier = self.attack_wild(attack);
+        let mut attack_modifier = dmg_modifier;
+        if self.dual_wielding() {
             attack_modifier -= 2;
         }
         #[allow(clippy::single_match_else, reason = "better readability")]
-        let attacks = match self.try_to_hit_with_bennie(opponent, num_rolls, attack_modifier) {
-            Ok(results) => results,
-            Err(CriticalMiss) => {
-                self.critical_fail(true);
-                return;
-            }
-        };
-        for attack in attacks {
-            self.do_damage(true, opponent, attack, dmg_modifier, false);
+        let attacks =
+            match self.try_to_hit_with_bennie(opponent, num_rolls, attack, attack_modifier) {
+                Ok(results) => results,
+                Err(CriticalMiss) => {
+                    self.critical_fail(AttackSource::Primary);
+                    return;
+                }
+            };
+        for attack_result in attacks {
+            self.do_damage(
+                AttackSource::Primary,
+                opponent,
+                attack_result,
+                dmg_modifier,
+                false,
+            );
         }
     }
 
     fn attack_with_second_weapon(&mut self, opponent: &mut Fighter) {
-        let mut attack_modifier = 0;
-        let mut dmg_modifier = 0;
-        if self.character.passive_modifiers.attack_wild.is_set() {
-            self.attacked_wild = true;
-            attack_modifier += 2;
-            dmg_modifier += 2;
-        }
-        if !self.character.edges.beidhandig.is_set() {
-            attack_modifier -= 2;
-        }
-        if self.character.weapon.active && !self.character.edges.beidhandiger_kampf.is_set() {
+        let attack = AttackKind::Secondary;
+        let dmg_modifier = self.attack_wild(attack);
+        // off-hand
+        let mut attack_modifier = dmg_modifier - 2;
+        if self.dual_wielding() {
             attack_modifier -= 2;
         }
 
         #[allow(clippy::single_match_else, reason = "better readability")]
-        let attacks = match self.try_to_hit_with_bennie(opponent, 1, attack_modifier) {
+        let attacks = match self.try_to_hit_with_bennie(opponent, 1, attack, attack_modifier) {
             Ok(results) => results,
             Err(CriticalMiss) => {
-                self.critical_fail(false);
+                self.critical_fail(AttackSource::Secondary);
                 return;
             }
         };
         let mut attacks = attacks.into_iter();
         if let Some(attack) = attacks.next() {
-            self.do_damage(false, opponent, attack, dmg_modifier, false);
+            self.do_damage(
+                AttackSource::Secondary,
+                opponent,
+                attack,
+                dmg_modifier,
+                false,
+            );
         }
         debug_assert!(
             attacks.next().is_none(),
@@ -233,9 +556,39 @@ impl Fighter {
         );
     }
 
+    /// all natural attacks are made in the same action, without off-hand penalties
+    fn attack_with_natural_weapons(&mut self, opponent: &mut Fighter) {
+        for index in 0..NaturalAttacks::COUNT {
+            if !self.character.natural_attacks.get(index).active() {
+                continue;
+            }
+            if self.shaken || opponent.is_dead() {
+                return;
+            }
+            let attack = AttackKind::Primary;
+            let source = AttackSource::Natural(index);
+            let dmg_modifier = self.attack_wild(attack);
+            #[allow(clippy::single_match_else, reason = "better readability")]
+            let attacks = match self.try_to_hit_with_bennie(opponent, 1, attack, dmg_modifier) {
+                Ok(results) => results,
+                Err(CriticalMiss) => {
+                    self.critical_fail(source);
+                    return;
+                }
+            };
+            for attack_result in attacks {
+                let hit = attack_result != AttackResult::Miss;
+                let damage = self.do_damage(source, opponent, attack_result, dmg_modifier, false);
+                if let Some(stats) = self.fight_stats.as_ref() {
+                    stats.borrow_mut().add_natural_attack(index, hit, damage);
+                }
+            }
+        }
+    }
+
     fn wanna_do_rundumschlag(&self, opponents: &[Rc<RefCell<Fighter>>]) -> bool {
         // don't do rundumschlag if we don't have it, duh
-        if !self.character.edges.rundumschlag.is_set() {
+        if !self.has_capability(Capability::SweepAttack) || self.cramped() {
             return false;
         }
 
@@ -250,17 +603,11 @@ impl Fighter {
     }
 
     fn do_rundumschlag(&mut self, opponents: &[Rc<RefCell<Fighter>>]) {
-        let mut attack_modifier = 0;
-        let mut dmg_modifier = 0;
-        if self.character.passive_modifiers.attack_wild.is_set() {
-            self.attacked_wild = true;
-            attack_modifier += 2;
-            dmg_modifier += 2;
-        }
+        let attack = AttackKind::Sweep;
+        let dmg_modifier = self.attack_wild(attack);
+        let mut attack_modifier = dmg_modifier;
         // only attack with primary weapon
-        if self.character.secondary_weapon.active
-            && !self.character.edges.beidhandiger_kampf.is_set()
-        {
+        if self.dual_wielding() {
             attack_modifier -= 2;
         }
 
@@ -268,7 +615,7 @@ impl Fighter {
             let roll = match self.roll_attack_dice(1) {
                 Ok(mut rolls) => rolls.pop(), // we rolled with 1
                 Err(RollError::CriticalFail) => {
-                    self.critical_fail(true);
+                    self.critical_fail(AttackSource::Primary);
                     return;
                 }
                 Err(RollError::Fail) => None,
@@ -279,8 +626,12 @@ impl Fighter {
                     .iter()
                     .map(|opponent| opponent.borrow_mut())
                     .map(|opponent| {
-                        let result =
-                            self.try_to_hit_without_bennie(&opponent, roll, attack_modifier);
+                        let result = self.try_to_hit_without_bennie(
+                            &opponent,
+                            roll,
+                            attack,
+                            attack_modifier,
+                        );
                         (opponent, result)
                     })
                     .collect()
@@ -294,10 +645,11 @@ impl Fighter {
                 .count()
                 .try_into()
                 .unwrap_or(u8::MAX);
-            if count_hits == 0 && self.character.bennies.use_for_attack.is_set() && self.bennies > 0
+            if count_hits == 0
+                && self.character.bennies.use_for_attack.is_set()
+                && self.spend_bennie()
             {
                 // use a benny and reroll if we can...
-                self.bennies -= 1;
                 continue;
             }
 
@@ -316,11 +668,22 @@ impl Fighter {
         };
 
         for (mut opponent, attack_result) in attack_rolls {
-            self.do_damage(true, &mut opponent, attack_result, dmg_modifier, false);
+            self.do_damage(
+                AttackSource::Primary,
+                &mut opponent,
+                attack_result,
+                dmg_modifier,
+                false,
+            );
         }
     }
 
     fn do_full_attack(&mut self, opponents: &[Rc<RefCell<Fighter>>]) -> ActionResult<()> {
+        if self.character.natural_attacks.any_active() {
+            let mut opponent = self.pick_opponent(opponents)?;
+            self.attack_with_natural_weapons(&mut opponent);
+            return Ok(());
+        }
         if self.character.weapon.active {
             if self.wanna_do_rundumschlag(opponents) {
                 self.do_rundumschlag(opponents);
@@ -347,27 +710,21 @@ impl Fighter {
         if !self.character.weapon.active {
             return;
         }
-        let mut attack_modifier = 0;
-        let mut dmg_modifier = 0;
-        if self.character.edges.erbarmungslos.is_set()
-            && self.character.passive_modifiers.attack_wild.is_set()
-        {
-            self.attacked_wild = true;
-            attack_modifier += 2;
-            dmg_modifier += 2;
-        }
+        let attack = AttackKind::Counter;
+        let dmg_modifier = self.attack_wild(attack);
+        let attack_modifier = dmg_modifier;
 
         #[allow(clippy::single_match_else, reason = "better readability")]
-        let attacks = match self.try_to_hit_with_bennie(opponent, 1, attack_modifier) {
+        let attacks = match self.try_to_hit_with_bennie(opponent, 1, attack, attack_modifier) {
             Ok(results) => results,
             Err(CriticalMiss) => {
-                self.critical_fail(true);
+                self.critical_fail(AttackSource::Primary);
                 return;
             }
         };
         let mut attacks = attacks.into_iter();
         if let Some(attack) = attacks.next() {
-            self.do_damage(true, opponent, attack, dmg_modifier, false);
+            self.do_damage(AttackSource::Primary, opponent, attack, dmg_modifier, false);
         }
         debug_assert!(
             attacks.next().is_none(),
@@ -375,12 +732,72 @@ impl Fighter {
         );
     }
 
+    /// natural attacks replace weapons, otherwise the weapon with the highest damage is used
+    fn best_attack(&self) -> Option<AttackSource> {
+        let character = &self.character;
+        if character.natural_attacks.any_active() {
+            return (0..NaturalAttacks::COUNT)
+                .filter(|&index| character.natural_attacks.get(index).active())
+                .max_by_key(|&index| u8::from(character.natural_attacks.get(index).damage))
+                .map(AttackSource::Natural);
+        }
+        let primary = character
+            .weapon
+            .active
+            .then_some((AttackSource::Primary, character.weapon.damage));
+        let secondary = character
+            .secondary_weapon
+            .active
+            .then_some((AttackSource::Secondary, character.secondary_weapon.damage));
+        // the last maximum wins, so the primary weapon is preferred on a tie
+        [secondary, primary]
+            .into_iter()
+            .flatten()
+            .max_by_key(|&(_source, damage)| u8::from(damage))
+            .map(|(source, _damage)| source)
+    }
+
+    /// a single attack with our best attack on an opponent withdrawing from melee
+    ///
+    /// Bennies are saved for our own turn.
+    fn free_attack(&mut self, opponent: &mut Fighter) {
+        let Some(source) = self.best_attack() else {
+            return;
+        };
+        let attack = AttackKind::Free;
+        let dmg_modifier = self.attack_wild(attack);
+        let mut attack_modifier = dmg_modifier;
+        if source == AttackSource::Secondary {
+            // off-hand
+            attack_modifier -= 2;
+        }
+
+        let attack_result = match self.roll_attack_dice(1) {
+            Ok(mut rolls) => rolls.pop().map_or(AttackResult::Miss, |roll| {
+                self.try_to_hit_without_bennie(opponent, roll, attack, attack_modifier)
+            }),
+            Err(RollError::CriticalFail) => {
+                self.critical_fail(source);
+                return;
+            }
+            Err(RollError::Fail) => AttackResult::Miss,
+        };
+        if attack_result != AttackResult::Miss {
+            if let Some(stats) = self.fight_stats.as_ref() {
+                stats.borrow_mut().add_hits_dealt(1);
+            }
+            if let Some(stats) = opponent.fight_stats.as_ref() {
+                stats.borrow_mut().add_hits_received(1);
+            }
+        }
+        self.do_damage(source, opponent, attack_result, dmg_modifier, false);
+    }
+
     fn pick_opponent<'o>(
         &self,
         opponents: &'o [Rc<RefCell<Fighter>>],
     ) -> ActionResult<RefMut<'o, Fighter>> {
-        let opponent = opponents
-            .first()
+        let opponent = Self::target(opponents)
             .expect("fight should be over if opponent list is empty")
             .borrow_mut();
         if opponent.is_dead() {
@@ -389,22 +806,73 @@ impl Fighter {
         Ok(opponent)
     }
 
-    pub fn action(&mut self, opponents: &[Rc<RefCell<Fighter>>]) {
+    /// returns `true` if we have an action left, which can also be held
+    pub fn start_turn(&mut self) -> bool {
         self.fell = false;
         self.attacked_wild = false;
-        if !self.unshake() {
+        self.has_action = self.unshake();
+        if self.has_action && self.weapon_lost {
+            // picking up the weapon takes the action
+            self.weapon_lost = false;
+            self.has_action = false;
+        }
+        self.has_action
+    }
+
+    /// hold the action to interrupt the turn of our target
+    pub fn wants_to_hold(&self) -> bool {
+        let tactics = &self.character.tactics;
+        tactics.hold.is_set() || (self.joker && tactics.hold_joker.is_set())
+    }
+
+    /// take the action left after [`Self::start_turn`], possibly after holding it
+    ///
+    /// `allies` is our whole group, including this fighter.
+    pub fn action(&mut self, opponents: &[Rc<RefCell<Fighter>>], allies: &[Rc<RefCell<Fighter>>]) {
+        // an interrupting opponent might have shaken us in between
+        if self.has_action && !self.shaken {
+            self.take_action(opponents, allies);
+        }
+        self.has_action = false;
+        self.modifiers.tick_turn();
+    }
+
+    fn take_action(&mut self, opponents: &[Rc<RefCell<Fighter>>], allies: &[Rc<RefCell<Fighter>>]) {
+        self.drink_elixir();
+
+        // bodyguards go for whoever threatens their ward
+        let targets = self
+            .attacker_of_ward(opponents, allies)
+            .map_or(opponents, std::slice::from_ref);
+
+        if self.heal(allies) {
             return;
         }
 
-        if self.weapon_lost {
-            self.weapon_lost = false;
+        if self.cast_spell(targets) {
+            return;
+        }
+
+        if self.shoot(targets) {
+            return;
+        }
+
+        if self.throw_knife(targets) {
             return;
         }
 
-        // take a step forward
-        if let Err(NoOpponentLeft) = self.step_forward(opponents) {
+        if self.covered(allies) {
+            // the front rank keeps the opponents away from us
+            self.attack_from_back_rank(targets);
             return;
         }
+
+        // take a step forward, limping or wading there takes the whole action
+        match self.step_forward(targets) {
+            Err(NoOpponentLeft) => return,
+            Ok(true) if self.slowed_down() => return,
+            Ok(_) => (),
+        }
         if self.character.bennies.use_against_step_back.is_set() {
             self.unshake_with_bennie();
         }
@@ -413,7 +881,7 @@ impl Fighter {
             return;
         }
 
-        if let Err(NoOpponentLeft) = self.do_full_attack(opponents) {
+        if let Err(NoOpponentLeft) = self.do_full_attack(targets) {
             return;
         }
 
@@ -422,33 +890,57 @@ impl Fighter {
     }
 
     fn apply_wound_penalty(&self, roll: &mut Roll) {
-        if self.berserker || self.character.passive_modifiers.no_wound_penalty.is_set() {
+        if self.raging()
+            || self.undead()
+            || self.character.passive_modifiers.no_wound_penalty.is_set()
+        {
             return;
         }
-        let wound_penalty: i8 = match self.passive_stats.life {
-            0..=10 => 3,
-            11..=20 => 1,
-            21.. => 0,
+        if let Some(torso_wounds) = self.torso_wound_penalty() {
+            *roll -= torso_wounds;
+            return;
+        }
+        let life = self.passive_stats.life;
+        let wound_penalty: i8 = match life {
+            _ if life <= self.rules.heavy_wound_threshold => 3,
+            _ if life <= self.rules.light_wound_threshold => 1,
+            _ => 0,
         };
         *roll -= wound_penalty;
     }
 
     fn apply_gangup(opponent: &Self, roll: &mut Roll) {
-        let opponent_has_two_weapons = opponent.character.edges.kampfkunstler.is_set()
-            || (opponent.character.weapon.active && opponent.character.secondary_weapon.active);
-        if opponent.character.edges.fechten_m2w.is_set() && opponent_has_two_weapons {
+        let distance_map = opponent.distance_map.borrow();
+        let count_attackers = distance_map.count_attackers(opponent);
+        if count_attackers <= 1 {
             return;
         }
 
-        let distance_map = opponent.distance_map.borrow();
-        let count_attackers = distance_map.all_base_contacts(opponent).count();
-        let gangup: u8 = (count_attackers - 1).min(4).try_into().unwrap();
+        let ctx = EdgeContext {
+            dual_wielding: opponent.dual_wielding()
+                || opponent.has_capability(Capability::MartialArtist),
+            ..opponent.edge_context(None, None)
+        };
+        let cap = opponent
+            .character
+            .edges
+            .gang_up_cap(ctx)
+            .unwrap_or(opponent.rules.gang_up_cap);
+        let cap = if opponent.cramped() { cap.min(1) } else { cap };
+        let gangup: u8 = (count_attackers - 1).min(cap.into()).try_into().unwrap();
         *roll += gangup;
     }
 
+    fn apply_timed_modifiers(&self, target: Target, roll: &mut Roll) {
+        *roll += self.modifiers.get(target);
+        if target != Target::Rolls {
+            *roll += self.modifiers.get(Target::Rolls);
+        }
+    }
+
     fn apply_joker(&self, roll: &mut Roll) {
         if self.joker {
-            *roll += 2_u8;
+            *roll += self.rules.joker_bonus;
         }
     }
 
@@ -456,92 +948,32 @@ impl Fighter {
         if !self.joker {
             return;
         }
-        *roll += 2_u8;
-        if !self.character.edges.machtiger_hieb.is_set() {
+        *roll += self.rules.joker_bonus;
+        if !self.has_capability(Capability::DoubleJokerDamage) {
             return;
         }
         *roll *= 2_u8;
     }
 
-    fn enable_berserker(&mut self) {
-        if self.character.edges.berserker != Edge3::None {
-            self.berserker = true;
-        }
-    }
-
-    fn apply_berserker_attack(&self, roll: &mut Roll) {
-        if self.berserker {
-            *roll += 2_u8;
-        }
-    }
-
-    fn apply_berserker_damage(&self, roll: &mut Roll) {
-        if self.berserker {
-            *roll += 2_u8;
-        }
-    }
-
-    fn apply_kampfreflexe(&self, roll: &mut Roll) {
-        if self.character.edges.kampfreflexe.is_set() {
-            *roll += 2_u8;
-        }
-    }
-
-    fn apply_piercing(&self, opponent: &Self, roll: &mut Roll) {
-        let piercing = i8::from(self.character.weapon.piercing);
+    fn apply_piercing(&self, source: AttackSource, opponent: &Self, roll: &mut Roll) {
+        let piercing = match source {
+            AttackSource::Primary => self.character.weapon.piercing,
+            AttackSource::Secondary => self.character.secondary_weapon.piercing,
+            AttackSource::Natural(index) => self.character.natural_attacks.get(index).piercing,
+        };
+        let piercing = i8::from(piercing);
         let armor = i8::from(opponent.character.armor.torso);
         *roll += piercing.min(armor);
     }
 
-    fn apply_tuchfühlung_to_attack(&self, opponent: &Self, roll: &mut Roll) {
-        if self.character.edges.tuchfuhlung == Edge3::Improved {
-            let reach = i8::from(opponent.character.weapon.reach);
-            *roll += 1 + reach;
-        }
-    }
-
-    fn apply_tuchfühlung_to_parry(&self, opponent: &Self, parry: &mut u8) {
-        if self.character.edges.tuchfuhlung != Edge3::None {
-            let reach = i8::from(opponent.character.weapon.reach)
-                .try_into()
-                .unwrap_or(0);
-            *parry += 1 + reach;
-        }
-    }
-
-    fn trigger_riposte(&mut self, opponent: &mut Self) {
-        if self.riposte_done && self.character.edges.riposte != Edge3::Improved {
-            return;
-        }
-
-        if self.character.bennies.use_for_special_attacks.is_set() {
-            // if we would be able to do riposte, try to unshake if necessary
-            self.unshake_with_bennie();
-        }
-
-        if self.shaken {
-            return;
-        }
-
-        self.riposte_done = true;
-
-        self.do_special_attack(opponent);
-    }
-
-    fn trigger_erstschlag(&mut self, opponent: &mut Self, base_contact_to_target: bool) {
-        match (self.character.edges.erstschlag, self.erstschlag_done) {
-            (Edge3::None, _) => return,
-            (Edge3::Normal, true) => return,
-            (Edge3::Normal, false) => (),
-            (Edge3::Improved, _) => (),
-        }
-
-        if !base_contact_to_target {
+    /// counter attack triggered by an edge, e.g. riposte or erstschlag
+    fn counter_attack(&mut self, edge: EdgeId, once_per_round: bool, opponent: &mut Self) {
+        if once_per_round && self.counters_done.contains(&edge) {
             return;
         }
 
         if self.character.bennies.use_for_special_attacks.is_set() {
-            // if we would be able to do erstschlag, try to unshake if necessary
+            // if we would be able to counter, try to unshake if necessary
             self.unshake_with_bennie();
         }
 
@@ -550,15 +982,15 @@ impl Fighter {
             return;
         }
 
-        // do erstschlag
+        self.counters_done.push(edge);
         self.do_special_attack(opponent);
-        self.erstschlag_done = true;
     }
 
     pub fn dex_roll(&self) -> Result<Roll, RollError> {
-        let mut roll = roller().roll_attribute(self.character.attributes.ges)?;
+        let mut roll = roller().roll_attribute(self.character.attributes.ges, self.wild_card())?;
         self.apply_joker(&mut roll);
         self.apply_wound_penalty(&mut roll);
+        self.apply_timed_modifiers(Target::Rolls, &mut roll);
         Ok(roll)
     }
 
@@ -574,12 +1006,25 @@ impl Fighter {
         false
     }
 
+    /// own bennies are used first, then the pool of the group; returns `false` if there are none
+    fn spend_bennie(&mut self) -> bool {
+        if self.bennies > 0 {
+            self.bennies -= 1;
+            return true;
+        }
+        let pool = &self.pool.shared;
+        if pool.get() == 0 || !self.wild_card() {
+            return false;
+        }
+        pool.set(pool.get() - 1);
+        true
+    }
+
     /// return `true` if bennie was used to unshake
     fn unshake_with_bennie(&mut self) -> bool {
-        if !self.shaken || self.bennies == 0 {
+        if !self.shaken || !self.spend_bennie() {
             return false;
         }
-        self.bennies -= 1;
         self.shaken = false;
         true
     }
@@ -590,18 +1035,20 @@ impl Fighter {
         if !self.shaken {
             return true;
         }
-        if self.interrupted {
-            self.interrupted = false;
-            return false;
-        }
-        let mut roll = match roller().roll_attribute(self.character.attributes.wil) {
-            Ok(roll) => roll,
-            Err(RollError::CriticalFail) => return false,
-            Err(RollError::Fail) => return false,
-        };
+        let mut roll =
+            match roller().roll_attribute(self.character.attributes.wil, self.wild_card()) {
+                Ok(roll) => roll,
+                Err(RollError::CriticalFail) => return false,
+                Err(RollError::Fail) => return false,
+            };
         self.apply_wound_penalty(&mut roll);
         self.apply_joker(&mut roll);
-        self.apply_kampfreflexe(&mut roll);
+        roll += self.edge_modifier(|hooks| hooks.unshake, None, None);
+        roll += self.aura.unshake;
+        if self.undead() {
+            roll += 2_u8;
+        }
+        self.apply_timed_modifiers(Target::Rolls, &mut roll);
         match roll.eval() {
             RollResult::Fail => false,
             RollResult::Success => {
@@ -623,7 +1070,7 @@ impl Fighter {
     }
 
     fn unarmed(&self, opponent: &Self) -> bool {
-        if self.character.edges.kampfkunstler.is_set() {
+        if self.keeps_weapon() {
             return false;
         }
         if self.weapon_lost {
@@ -638,33 +1085,48 @@ impl Fighter {
         roller().roll_skill_with_n_dice(
             self.character.skills.kampfen,
             num_skill_dice,
-            self.berserker,
+            self.raging(),
+            self.wild_card(),
         )
     }
 
-    fn try_to_hit_without_bennie(&self, opponent: &Self, roll: Roll, modifier: i8) -> AttackResult {
+    fn try_to_hit_without_bennie(
+        &self,
+        opponent: &Self,
+        roll: Roll,
+        attack: AttackKind,
+        modifier: i8,
+    ) -> AttackResult {
         let opponent_fell_modifier: u8 = if opponent.fell { 2 } else { 0 };
-        let opponent_berserker_modifier: u8 = if opponent.berserker { 2 } else { 0 };
         let opponent_wild_modifier: u8 = if opponent.attacked_wild { 2 } else { 0 };
         let opponent_weapon_lost_modifier: u8 = if opponent.unarmed(self) { 2 } else { 0 };
         let mut opponent_parry = opponent.passive_stats.parry;
         opponent_parry = opponent_parry.saturating_sub(opponent_fell_modifier);
-        opponent_parry = opponent_parry.saturating_sub(opponent_berserker_modifier);
         opponent_parry = opponent_parry.saturating_sub(opponent_wild_modifier);
         opponent_parry = opponent_parry.saturating_sub(opponent_weapon_lost_modifier);
-        opponent.apply_tuchfühlung_to_parry(self, &mut opponent_parry);
+        opponent_parry = opponent_parry.saturating_add_signed(opponent.edge_modifier(
+            |hooks| hooks.parry,
+            Some(self),
+            None,
+        ));
+        opponent_parry =
+            opponent_parry.saturating_add_signed(opponent.modifiers.get(Target::Parry));
 
         let mut roll = roll;
         roll += modifier;
         roll += i8::from(self.character.passive_modifiers.attack);
+        roll += self.scale_modifier(opponent);
         self.apply_wound_penalty(&mut roll);
         Self::apply_gangup(opponent, &mut roll);
+        Self::apply_the_drop(opponent, &mut roll);
+        self.apply_environment(false, &mut roll);
         self.apply_joker(&mut roll);
-        self.apply_berserker_attack(&mut roll);
         if self.character.passive_modifiers.attack_head.is_set() {
             roll -= 4_u8;
         }
-        self.apply_tuchfühlung_to_attack(opponent, &mut roll);
+        roll += self.edge_modifier(|hooks| hooks.attack, Some(opponent), Some(attack));
+        self.apply_timed_modifiers(Target::Attack, &mut roll);
+        self.apply_arm_wounds(&mut roll);
         roll -= opponent_parry;
 
         match roll.as_i8() {
@@ -678,17 +1140,17 @@ impl Fighter {
         &mut self,
         opponent: &Fighter,
         num_skill_dice: usize,
+        attack: AttackKind,
         modifier: i8,
     ) -> Result<Vec<AttackResult>, CriticalMiss> {
-        let all_fail = (0..num_skill_dice).map(|_| AttackResult::Miss).collect();
         let rolls = match self.roll_attack_dice(num_skill_dice) {
             Ok(rolls) => rolls,
             Err(RollError::CriticalFail) => return Err(CriticalMiss),
-            Err(RollError::Fail) => return Ok(all_fail),
+            Err(RollError::Fail) => return Ok(vec![AttackResult::Miss; num_skill_dice]),
         };
         let attacks: Vec<_> = rolls
             .into_iter()
-            .map(|roll| self.try_to_hit_without_bennie(opponent, roll, modifier))
+            .map(|roll| self.try_to_hit_without_bennie(opponent, roll, attack, modifier))
             .collect();
 
         let count_hits = attacks
@@ -697,9 +1159,9 @@ impl Fighter {
             .count()
             .try_into()
             .unwrap_or(u8::MAX);
-        if count_hits == 0 && self.character.bennies.use_for_attack.is_set() && self.bennies > 0 {
-            self.bennies -= 1;
-            self.try_to_hit_with_bennie(opponent, num_skill_dice, modifier)
+        if count_hits == 0 && self.character.bennies.use_for_attack.is_set() && self.spend_bennie()
+        {
+            self.try_to_hit_with_bennie(opponent, num_skill_dice, attack, modifier)
         } else {
             if let Some(stats) = self.fight_stats.as_ref() {
                 stats.borrow_mut().add_hits_dealt(count_hits);
@@ -718,86 +1180,112 @@ impl Fighter {
             opponent.character.armor.torso
         };
         *damage -= i8::from(armor);
+        *damage -= opponent.modifiers.get(Target::Armor);
     }
 
-    fn apply_opponent_berserker_rob(&self, opponent: &Self, damage: &mut Roll) {
-        if !opponent.berserker {
-            return;
+    fn roll_damage(&self, source: AttackSource) -> Roll {
+        let strength = self.character.attributes.sta;
+        match source {
+            AttackSource::Primary => roller().roll_weapon_damage(&self.character.weapon, strength),
+            AttackSource::Secondary => {
+                roller().roll_weapon_damage(&self.character.secondary_weapon, strength)
+            }
+            AttackSource::Natural(index) => {
+                roller().roll_natural_damage(self.character.natural_attacks.get(index), strength)
+            }
         }
-        *damage -= 2i8;
     }
 
+    /// returns the damage that got through
     fn do_damage(
         &mut self,
-        primary_weapon: bool,
+        source: AttackSource,
         opponent: &mut Self,
         attack_result: AttackResult,
         modifier: i8,
         self_damage: bool,
-    ) {
+    ) -> u8 {
         // might have gone shaken in between due to riposte
         if self.shaken {
-            return;
+            return 0;
         }
 
         let raise = match attack_result {
             AttackResult::Miss => {
-                opponent.trigger_riposte(self);
-                return;
+                opponent.apply_edge_triggers(|hooks| hooks.on_miss, Some(self));
+                return 0;
             }
             AttackResult::Hit => false,
             AttackResult::Raise => true,
         };
 
-        let mut damage = if primary_weapon {
-            roller().roll_weapon_damage(&self.character.weapon, self.character.attributes.sta)
-        } else {
-            roller().roll_weapon_damage(
-                &self.character.secondary_weapon,
-                self.character.attributes.sta,
-            )
-        };
-
+        let mut damage = self.roll_damage(source);
         damage += roller().roll_attribute_without_wild_die(self.character.attributes.sta);
         if raise {
-            let more_crit = primary_weapon && self.character.weapon.more_crit.is_set()
-                || !primary_weapon && self.character.secondary_weapon.more_crit.is_set();
+            let more_crit = match source {
+                AttackSource::Primary => self.character.weapon.more_crit.is_set(),
+                AttackSource::Secondary => self.character.secondary_weapon.more_crit.is_set(),
+                AttackSource::Natural(_) => false,
+            };
             damage += if more_crit {
                 roller().roll_raise_d10()
             } else {
                 roller().roll_raise()
             };
         }
-        self.apply_piercing(opponent, &mut damage);
-        self.apply_berserker_damage(&mut damage);
-        if self.character.edges.ubertolpeln.is_set() && opponent.shaken {
-            damage += 4_u8;
-        }
+        self.apply_piercing(source, opponent, &mut damage);
+        damage += self.edge_modifier(|hooks| hooks.damage, Some(opponent), None);
+        damage += self.modifiers.get(Target::Damage);
         damage += modifier;
         if self.character.passive_modifiers.attack_head.is_set() {
             damage += 6_u8;
         }
+        Self::apply_the_drop(opponent, &mut damage);
         self.apply_joker_to_damage(&mut damage);
-        if u8::from(damage) < opponent.passive_stats.robustness {
-            if !self_damage && self.character.bennies.use_for_damage.is_set() && self.bennies > 0 {
-                self.bennies -= 1;
-                self.do_damage(
-                    primary_weapon,
-                    opponent,
-                    attack_result,
-                    modifier,
-                    self_damage,
-                );
+        if u8::from(damage) < opponent.robustness() {
+            if !self_damage && self.character.bennies.use_for_damage.is_set() && self.spend_bennie()
+            {
+                return self.do_damage(source, opponent, attack_result, modifier, self_damage);
             }
-            return;
+            return 0;
+        }
+
+        let damage = self.inflict_damage(opponent, damage);
+        if !self_damage {
+            self.afflict(source, opponent, damage);
+        }
+        damage
+    }
+
+    /// lose life and keep track of the wounds, for hits as well as damage over time
+    fn take_damage(&mut self, damage: u8, aimed_at: Option<Location>) {
+        self.passive_stats.life = self.passive_stats.life.saturating_sub(damage);
+        self.wounded |= damage > 0;
+        self.receive_wounds(damage, aimed_at);
+        if self.is_dead() {
+            self.disengage();
         }
+    }
 
-        damage -= opponent.passive_stats.robustness;
+    /// apply damage that reached the robustness of the opponent, returns the damage that got
+    /// through
+    fn inflict_damage(&mut self, opponent: &mut Self, mut damage: Roll) -> u8 {
+        damage -= opponent.robustness();
+        if opponent.shaken && opponent.character.creature.hardy.is_set() && damage.as_i8() < 4 {
+            // hardy creatures only get wounded by a raise while shaken
+            return 0;
+        }
         self.apply_opponents_armor(opponent, &mut damage);
-        self.apply_opponent_berserker_rob(opponent, &mut damage);
-        opponent.passive_stats.life -= damage;
+        damage += opponent.edge_modifier(|hooks| hooks.damage_received, Some(self), None);
+        let aimed_at = self
+            .character
+            .passive_modifiers
+            .attack_head
+            .is_set()
+            .then_some(Location::Head);
+        opponent.take_damage(damage.as_u8(), aimed_at);
         opponent.shaken = true;
-        opponent.enable_berserker();
+        opponent.apply_edge_triggers(|hooks| hooks.on_hit, None);
 
         if let Some(stats) = self.fight_stats.as_ref() {
             stats.borrow_mut().add_damage_dealt(damage.into());
@@ -805,47 +1293,75 @@ impl Fighter {
         if let Some(stats) = opponent.fight_stats.as_ref() {
             stats.borrow_mut().add_damage_received(damage.into());
         }
+        damage.as_u8()
+    }
 
-        // instead of implementing interrupting logic, we can just assume that
-        // damage done while holding a joker just interrupts the opponent.
-        if self.joker && !opponent.joker {
-            opponent.interrupted = true;
+    /// the fumble table depends on what we attacked with
+    fn melee_fumble_table(&self, source: AttackSource) -> &FumbleTable {
+        let tables = &self.rules.fumble_tables;
+        let (unarmed, kind) = match source {
+            AttackSource::Primary => (self.character.weapon.unarmed(), self.character.weapon.kind),
+            AttackSource::Secondary => (
+                self.character.secondary_weapon.unarmed(),
+                self.character.secondary_weapon.kind,
+            ),
+            AttackSource::Natural(_) => return &tables.natural,
+        };
+        if unarmed {
+            &tables.natural
+        } else {
+            tables.weapon(kind)
         }
     }
 
-    fn critical_fail(&mut self, primary_weapon: bool) {
-        let fail_result = CriticalFailResult::short_range();
+    fn break_weapon(&mut self, source: AttackSource) {
+        match source {
+            AttackSource::Primary => self.character.weapon = Weapon::default(),
+            AttackSource::Secondary => self.character.secondary_weapon.active = false,
+            AttackSource::Natural(_) => (),
+        }
+    }
 
-        match fail_result {
-            CriticalFailResult::WeaponDestroyed => {
-                // kampfkünstler not affected
-                if self.character.edges.kampfkunstler.is_set() {
+    fn critical_fail(&mut self, source: AttackSource) {
+        let fumble = roll_fumble(self.melee_fumble_table(source));
+
+        match fumble {
+            Fumble::Nothing => (),
+            Fumble::Shaken => self.shaken = true,
+            Fumble::WeaponBroken => {
+                if self.keeps_weapon() {
+                    return;
+                }
+                self.break_weapon(source);
+            }
+            Fumble::WeaponDestroyed => {
+                if self.keeps_weapon() {
                     return;
                 }
                 // handle this as defeat for now
                 self.passive_stats.life = 0;
             }
-            CriticalFailResult::Fell => {
+            Fumble::Fell => {
                 // actually also requires 2 pace, but treat it the same for now
                 self.shaken = true;
                 self.fell = true;
             }
-            CriticalFailResult::Tripped => {
+            Fumble::Tripped => {
                 self.shaken = true;
                 self.fell = true;
             }
-            CriticalFailResult::WeaponLost => {
-                self.weapon_lost = !self.character.edges.kampfkunstler.is_set();
+            Fumble::WeaponLost => {
+                self.weapon_lost = !self.keeps_weapon();
             }
-            CriticalFailResult::Injured => {
+            Fumble::Injured => {
                 let mut tmp = self.clone();
                 let modifier = if self.attacked_wild { 2 } else { 0 };
-                tmp.do_damage(primary_weapon, self, AttackResult::Hit, modifier, true);
+                tmp.do_damage(source, self, AttackResult::Hit, modifier, true);
             }
-            CriticalFailResult::HeavilyInjured => {
+            Fumble::HeavilyInjured => {
                 let mut tmp = self.clone();
                 let modifier = if self.attacked_wild { 2 } else { 0 };
-                tmp.do_damage(primary_weapon, self, AttackResult::Raise, modifier, true);
+                tmp.do_damage(source, self, AttackResult::Raise, modifier, true);
             }
         }
     }
@@ -858,47 +1374,23 @@ enum AttackResult {
     Raise,
 }
 
-enum CriticalFailResult {
-    WeaponDestroyed,
-    Fell,
-    Tripped,
-    WeaponLost,
-    Injured,
-    HeavilyInjured,
+fn roll_fumble(table: &FumbleTable) -> Fumble {
+    table.get(roller().roll_critical_fail_result().as_u8())
 }
 
-impl CriticalFailResult {
-    fn short_range() -> Self {
-        match roller().roll_critical_fail_result().as_u8() {
-            0..=1 => unreachable!(),
-            13.. => unreachable!(),
-            2 => Self::WeaponDestroyed,
-            3..=5 => Self::Fell,
-            6..=8 => Self::Tripped,
-            9..=10 => Self::WeaponLost,
-            11 => Self::Injured,
-            12 => Self::HeavilyInjured,
-        }
-    }
-
-    #[allow(dead_code)]
-    fn long_range() -> Self {
-        match roller().roll_critical_fail_result().as_u8() {
-            0..=1 => unreachable!(),
-            13.. => unreachable!(),
-            2..=3 => Self::WeaponDestroyed,
-            4..=10 => Self::WeaponLost, // actually it blocks or something, but for the sake of simplicity...
-            11..=12 => Self::Injured,
-        }
-    }
+#[derive(Debug, Default)]
+pub struct DistanceMap {
+    /// indexed by the distance id of the fighters
+    engagements: Vec<Engagement>,
 }
 
+/// the opponents a fighter is engaged with, base contact is kept on both sides
 #[derive(Debug, Default)]
-pub struct DistanceMap {
-    ids_left: Vec<u16>,
-    ids_right: Vec<u16>,
-    next_id: u16,
-    map: HashMap<(u16, u16), bool>,
+struct Engagement {
+    /// opponents in base contact
+    contacts: Vec<u16>,
+    /// opponents attacking with a reach weapon without base contact
+    reach_attackers: Vec<u16>,
 }
 
 impl DistanceMap {
@@ -906,50 +1398,345 @@ impl DistanceMap {
         Self::default()
     }
 
-    fn get_key(fighter_group: Group, fighter_id: u16, opponent_id: u16) -> (u16, u16) {
-        match fighter_group {
-            Group::Left => (fighter_id, opponent_id),
-            Group::Right => (opponent_id, fighter_id),
+    fn register_fighter(&mut self) -> u16 {
+        let fighter_id = self
+            .engagements
+            .len()
+            .try_into()
+            .expect("there should be fewer fighters than distance ids");
+        self.engagements.push(Engagement::default());
+        fighter_id
+    }
+
+    fn engagement(&self, fighter: &Fighter) -> &Engagement {
+        &self.engagements[usize::from(fighter.distance_id)]
+    }
+
+    fn engagement_mut(&mut self, fighter: &Fighter) -> &mut Engagement {
+        &mut self.engagements[usize::from(fighter.distance_id)]
+    }
+
+    /// stepping into base contact replaces attacks at reach between both
+    fn set_base_contact(&mut self, fighter: &Fighter, opponent: &Fighter, contact: bool) {
+        for (fighter, opponent) in [(fighter, opponent), (opponent, fighter)] {
+            let engagement = self.engagement_mut(fighter);
+            engagement.contacts.retain(|&id| id != opponent.distance_id);
+            engagement
+                .reach_attackers
+                .retain(|&id| id != opponent.distance_id);
+            if contact {
+                engagement.contacts.push(opponent.distance_id);
+            }
         }
     }
 
-    fn register_fighter(&mut self, group: Group) -> u16 {
-        let fighter_id = self.next_id;
-        self.next_id += 1;
+    fn base_contact(&self, fighter: &Fighter, opponent: &Fighter) -> bool {
+        self.engagement(fighter)
+            .contacts
+            .contains(&opponent.distance_id)
+    }
 
-        let (own_group, other_group) = match group {
-            Group::Left => (&mut self.ids_left, &mut self.ids_right),
-            Group::Right => (&mut self.ids_right, &mut self.ids_left),
-        };
+    /// the opponent is attacked with a reach weapon from a distance
+    fn engage_at_reach(&mut self, fighter: &Fighter, opponent: &Fighter) {
+        if self.base_contact(fighter, opponent) {
+            return;
+        }
+        let reach_attackers = &mut self.engagement_mut(opponent).reach_attackers;
+        if !reach_attackers.contains(&fighter.distance_id) {
+            reach_attackers.push(fighter.distance_id);
+        }
+    }
+
+    /// in base contact or attacking one another with a reach weapon
+    fn engaged(&self, fighter: &Fighter, opponent: &Fighter) -> bool {
+        self.base_contact(fighter, opponent)
+            || self
+                .engagement(fighter)
+                .reach_attackers
+                .contains(&opponent.distance_id)
+            || self
+                .engagement(opponent)
+                .reach_attackers
+                .contains(&fighter.distance_id)
+    }
+
+    /// the fighter is no longer engaged with anybody, neither in base contact nor at reach
+    fn withdraw(&mut self, fighter: &Fighter) {
+        for engagement in &mut self.engagements {
+            engagement.contacts.retain(|&id| id != fighter.distance_id);
+            engagement
+                .reach_attackers
+                .retain(|&id| id != fighter.distance_id);
+        }
+        let engagement = self.engagement_mut(fighter);
+        engagement.contacts.clear();
+        engagement.reach_attackers.clear();
+    }
+
+    /// number of opponents in base contact or attacking with a reach weapon, the fallen are
+    /// already withdrawn
+    fn count_attackers(&self, fighter: &Fighter) -> usize {
+        let engagement = self.engagement(fighter);
+        engagement.contacts.len() + engagement.reach_attackers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Spell;
+    use crate::app::group::PoolMode;
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_extras_get_no_bennies() {
+        let mut wild_card = Character::default();
+        wild_card.bennies.count.set(3);
+        let mut extra = wild_card.clone();
+        extra.experience.wild_card.decrement();
+
+        let wild_card = Fighter::for_test(wild_card, RuleSet::default());
+        let extra = Fighter::for_test(extra, RuleSet::default());
+
+        assert_eq!(wild_card.bennies, 3);
+        assert_eq!(extra.bennies, 0);
+    }
+
+    #[test]
+    fn test_undead_regenerate_and_know_no_fear() {
+        let mut creature = Character::default();
+        creature.creature.regeneration.set(3);
+        creature.creature.undead.increment();
+        let mut creature = Fighter::for_test(creature, RuleSet::default());
+        creature.passive_stats.life = creature.max_life - 5;
+
+        creature.new_round(None);
+        assert_eq!(creature.passive_stats.life, creature.max_life - 2);
+
+        creature.resist_fear(u8::MAX);
+        assert!(!creature.shaken);
+    }
 
-        own_group.push(fighter_id);
-        for other_id in other_group {
-            let key = Self::get_key(group, fighter_id, *other_id);
-            self.map.insert(key, false);
+    #[test]
+    fn test_all_natural_attacks_in_one_action() {
+        roller().seed(2);
+        let mut creature = Character::default();
+        for index in 0..NaturalAttacks::COUNT {
+            creature.natural_attacks.get_mut(index).damage.increment();
         }
+        let mut creature = Fighter::for_test(creature, RuleSet::default());
+        let stats = Rc::new(RefCell::new(FightStats::new()));
+        creature.fight_stats = Some(Rc::clone(&stats));
+        let opponent = creature.opponent_for_test(Character::default());
+        // survives every attack
+        opponent.borrow_mut().passive_stats.life = u8::MAX;
 
-        fighter_id
+        creature
+            .do_full_attack(&[Rc::clone(&opponent)])
+            .unwrap_or_else(|_| panic!("opponent is alive"));
+
+        for index in 0..NaturalAttacks::COUNT {
+            assert_eq!(stats.borrow().natural_attacks_made(index), 1);
+        }
     }
 
-    fn base_contact_mut(&mut self, fighter: &Fighter, opponent: &Fighter) -> &mut bool {
-        let key = Self::get_key(fighter.group(), fighter.distance_id, opponent.distance_id);
-        self.map
-            .get_mut(&key)
-            .expect("distance map entry should exist if fighter was registered")
+    #[test]
+    fn test_shared_bennies_are_spent_by_wild_cards() {
+        let pool = BennyPool::new(&GroupBennies {
+            count: 1,
+            mode: PoolMode::Both,
+        });
+        let mut first = Fighter::for_test(Character::default(), RuleSet::default());
+        first.pool = pool.clone();
+        first.bennies = 1;
+        let mut second = first.clone();
+        second.bennies = 0;
+        let mut extra = Character::default();
+        extra.experience.wild_card.decrement();
+        let mut extra = Fighter::for_test(extra, RuleSet::default());
+        extra.pool = pool;
+
+        // own bennies come first
+        assert!(first.spend_bennie());
+        assert_eq!(first.bennies, 0);
+        assert!(!extra.spend_bennie());
+        assert!(second.spend_bennie());
+        assert!(!first.spend_bennie());
     }
 
-    fn base_contact(&self, fighter: &Fighter, opponent: &Fighter) -> bool {
-        let key = Self::get_key(fighter.group(), fighter.distance_id, opponent.distance_id);
-        *self
-            .map
-            .get(&key)
-            .expect("distance map entry should exist if fighter was registered")
+    #[test]
+    fn test_narrow_place_caps_the_gang_up() {
+        let mut fighter = Fighter::for_test(Character::default(), RuleSet::default());
+        for _ in 0..3 {
+            let attacker = fighter.opponent_for_test(Character::default());
+            fighter
+                .distance_map
+                .borrow_mut()
+                .set_base_contact(&fighter, &attacker.borrow(), true);
+        }
+        let gang_up = |fighter: &Fighter| {
+            let mut roll = roller().roll_d6s(0);
+            Fighter::apply_gangup(fighter, &mut roll);
+            roll.as_i8()
+        };
+
+        assert_eq!(gang_up(&fighter), 2);
+        fighter.environment.narrow = true;
+        assert_eq!(gang_up(&fighter), 1);
     }
 
-    fn all_base_contacts(&self, fighter: &Fighter) -> impl Iterator<Item = bool> {
-        self.map
-            .iter()
-            .filter(|(key, _val)| key.0 == fighter.distance_id || key.1 == fighter.distance_id)
-            .map(|(_key, val)| *val)
+    #[test]
+    fn test_gang_up_counts_engaged_attackers() {
+        let fighter = Fighter::for_test(Character::default(), RuleSet::default());
+        let mut spear = Character::default();
+        spear.weapon.reach.set(1);
+        let attacker = fighter.opponent_for_test(Character::default());
+        let spearman = fighter.opponent_for_test(spear);
+        let _bystander = fighter.opponent_for_test(Character::default());
+        let gang_up = |fighter: &Fighter| {
+            let mut roll = roller().roll_d6s(0);
+            Fighter::apply_gangup(fighter, &mut roll);
+            roll.as_i8()
+        };
+
+        fighter
+            .distance_map
+            .borrow_mut()
+            .set_base_contact(&fighter, &attacker.borrow(), true);
+        assert_eq!(gang_up(&fighter), 0);
+        fighter
+            .distance_map
+            .borrow_mut()
+            .engage_at_reach(&spearman.borrow(), &fighter);
+        assert_eq!(gang_up(&fighter), 1);
+        spearman.borrow_mut().take_damage(u8::MAX, None);
+        assert_eq!(gang_up(&fighter), 0);
+    }
+
+    #[test]
+    fn test_joker_holds_only_by_tactic() {
+        let mut character = Character::default();
+        let mut fighter = Fighter::for_test(character.clone(), RuleSet::default());
+        fighter.joker = true;
+        assert!(!fighter.wants_to_hold());
+
+        character.tactics.hold_joker.increment();
+        let mut fighter = Fighter::for_test(character, RuleSet::default());
+        assert!(!fighter.wants_to_hold());
+        fighter.joker = true;
+        assert!(fighter.wants_to_hold());
+    }
+
+    #[test]
+    fn test_free_attack_uses_best_attack() {
+        let best_attack = |character: &Character| {
+            Fighter::for_test(character.clone(), RuleSet::default()).best_attack()
+        };
+        let mut character = Character::default();
+        assert_eq!(best_attack(&character), Some(AttackSource::Primary));
+
+        character.weapon.active = false;
+        assert_eq!(best_attack(&character), None);
+
+        character.secondary_weapon.active = true;
+        character.secondary_weapon.damage.increment();
+        assert_eq!(best_attack(&character), Some(AttackSource::Secondary));
+
+        character.natural_attacks.get_mut(0).damage.increment();
+        character.natural_attacks.get_mut(1).damage.increment();
+        character.natural_attacks.get_mut(1).damage.increment();
+        assert_eq!(best_attack(&character), Some(AttackSource::Natural(1)));
+    }
+
+    #[test]
+    fn test_step_back_only_when_safe_or_disengaging() {
+        let mut first_strike = Character::default();
+        first_strike.edges.increment(EdgeId::Erstschlag);
+        let mut fighter = Fighter::for_test(first_strike, RuleSet::default());
+        let opponent = fighter.opponent_for_test(Character::default());
+        let opponents = [Rc::clone(&opponent)];
+        let set_contact = |fighter: &Fighter, contact: bool| {
+            fighter.distance_map.borrow_mut().set_base_contact(
+                fighter,
+                &opponent.borrow(),
+                contact,
+            );
+        };
+        let in_contact = |fighter: &Fighter| {
+            fighter
+                .distance_map
+                .borrow()
+                .base_contact(fighter, &opponent.borrow())
+        };
+
+        // the opponent could hit us
+        set_contact(&fighter, true);
+        fighter.step_back(&opponents);
+        assert!(in_contact(&fighter));
+
+        opponent.borrow_mut().shaken = true;
+        fighter.step_back(&opponents);
+        assert!(!in_contact(&fighter));
+
+        // a reach weapon keeps the opponent at a distance
+        set_contact(&fighter, true);
+        opponent.borrow_mut().shaken = false;
+        fighter.character.weapon.reach.set(1);
+        fighter.step_back(&opponents);
+        assert!(!in_contact(&fighter));
+        fighter.character.weapon.reach.set(0);
+
+        // disengaging accepts the free attack
+        set_contact(&fighter, true);
+        opponent.borrow_mut().shaken = false;
+        fighter.character.tactics.disengage.increment();
+        fighter.passive_stats.life = u8::MAX;
+        fighter.step_back(&opponents);
+        assert!(!in_contact(&fighter));
+    }
+
+    #[test]
+    fn test_fallen_and_withdrawn_fighters_are_disengaged() {
+        let mut fighter = Character::default();
+        fighter.tactics.disengage.increment();
+        let mut fighter = Fighter::for_test(fighter, RuleSet::default());
+        let mut spear = Character::default();
+        spear.weapon.reach.set(1);
+        let spearman = fighter.opponent_for_test(spear);
+        let opponents = [Rc::clone(&spearman)];
+        let engaged = |fighter: &Fighter| {
+            fighter
+                .distance_map
+                .borrow()
+                .engaged(fighter, &spearman.borrow())
+        };
+        let engage_at_reach = |fighter: &Fighter| {
+            fighter
+                .distance_map
+                .borrow_mut()
+                .engage_at_reach(&spearman.borrow(), fighter);
+        };
+
+        engage_at_reach(&fighter);
+        assert!(engaged(&fighter));
+        fighter.step_back(&opponents);
+        assert!(!engaged(&fighter));
+
+        engage_at_reach(&fighter);
+        spearman.borrow_mut().take_damage(u8::MAX, None);
+        assert!(!engaged(&fighter));
+    }
+
+    #[test]
+    fn test_round_starts_without_card() {
+        let mut fighter = Fighter::for_test(Character::default(), RuleSet::default());
+        let source = Source::Spell(Spell::Armor);
+        let duration = Duration::Rounds(1);
+        fighter.modifiers.add(source, Target::Armor, 2, duration);
+
+        fighter.new_round(None);
+
+        assert!(!fighter.modifiers.has(source));
     }
 }
@@ -0,0 +1,170 @@
+//! Damage over time, i.e. poison and bleeding wounds.
+//!
+//! Afflictions are caused by hits of weapons or natural attacks and hurt the victim at the start
+//! of each round, until a Kon roll ends them.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::character::DamageOverTime;
+use crate::simulator::fight_report::FightStats;
+use crate::simulator::roller::{RollResult, roller};
+
+use super::wounds::Location;
+use super::{AttackSource, Fighter};
+
+/// Poison and bleeding a fighter suffers from, the same kind doesn't stack
+#[derive(Debug, Default, Clone)]
+pub struct Afflictions {
+    /// life lost each round
+    poison: u8,
+    /// life lost each round
+    bleeding: u8,
+    /// stats of the group that caused the afflictions
+    inflicted_by: Option<Rc<RefCell<FightStats>>>,
+}
+
+impl Afflictions {
+    fn is_empty(&self) -> bool {
+        self.poison == 0 && self.bleeding == 0
+    }
+}
+
+impl Fighter {
+    fn damage_over_time(&self, source: AttackSource) -> &DamageOverTime {
+        match source {
+            AttackSource::Primary => &self.character.weapon.damage_over_time,
+            AttackSource::Secondary => &self.character.secondary_weapon.damage_over_time,
+            AttackSource::Natural(index) => {
+                &self.character.natural_attacks.get(index).damage_over_time
+            }
+        }
+    }
+
+    /// called for hits that reached the robustness of the opponent
+    pub(super) fn afflict(&self, source: AttackSource, opponent: &mut Self, damage: u8) {
+        // undead neither bleed nor care about poison
+        if opponent.undead() {
+            return;
+        }
+        let damage_over_time = self.damage_over_time(source);
+        let poison = damage_over_time.poison();
+        let bleeding = if damage > 0 {
+            damage_over_time.bleeding()
+        } else {
+            0
+        };
+        if poison == 0 && bleeding == 0 {
+            return;
+        }
+
+        let afflictions = &mut opponent.afflictions;
+        if poison > afflictions.poison {
+            log::debug!(
+                "{} is poisoned ({poison})",
+                opponent.character.name.as_str()
+            );
+            afflictions.poison = poison;
+        }
+        if bleeding > afflictions.bleeding {
+            log::debug!(
+                "{} is bleeding ({bleeding})",
+                opponent.character.name.as_str()
+            );
+            afflictions.bleeding = bleeding;
+        }
+        afflictions.inflicted_by.clone_from(&self.fight_stats);
+    }
+
+    /// called at the start of each round
+    pub(super) fn suffer_afflictions(&mut self) {
+        if self.afflictions.is_empty() || self.is_dead() {
+            return;
+        }
+
+        let damage = self.afflictions.poison + self.afflictions.bleeding;
+        // poison and blood loss weaken the whole body
+        self.take_damage(damage, Some(Location::Torso));
+        log::debug!(
+            "{} loses {damage} LeP by poison and bleeding",
+            self.character.name.as_str()
+        );
+        if let Some(stats) = self.afflictions.inflicted_by.as_ref() {
+            stats.borrow_mut().add_damage_over_time_dealt(damage);
+        }
+        if let Some(stats) = self.fight_stats.as_ref() {
+            stats.borrow_mut().add_damage_over_time_received(damage);
+        }
+
+        if self.afflictions.poison > 0 && self.resists_affliction() {
+            log::debug!("{} overcomes the poison", self.character.name.as_str());
+            self.afflictions.poison = 0;
+        }
+        if self.afflictions.bleeding > 0 && self.resists_affliction() {
+            log::debug!("{} stops the bleeding", self.character.name.as_str());
+            self.afflictions.bleeding = 0;
+        }
+    }
+
+    fn resists_affliction(&self) -> bool {
+        let Ok(mut roll) = roller().roll_attribute(self.character.attributes.kon, self.wild_card())
+        else {
+            return false;
+        };
+        self.apply_wound_penalty(&mut roll);
+        roll.eval() != RollResult::Fail
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_weapon_inflicts_afflictions() {
+        let mut poisoner = Character::default();
+        poisoner.weapon.damage_over_time.poison.set(3);
+        poisoner.weapon.damage_over_time.bleeding.set(2);
+        let poisoner = Fighter::for_test(poisoner, RuleSet::default());
+        let mut opponent = Fighter::for_test(Character::default(), RuleSet::default());
+
+        // bleeding needs damage, poison doesn't
+        poisoner.afflict(AttackSource::Primary, &mut opponent, 0);
+        assert_eq!(
+            (opponent.afflictions.poison, opponent.afflictions.bleeding),
+            (3, 0)
+        );
+        poisoner.afflict(AttackSource::Primary, &mut opponent, 5);
+        assert_eq!(
+            (opponent.afflictions.poison, opponent.afflictions.bleeding),
+            (3, 2)
+        );
+
+        let mut undead = Character::default();
+        undead.creature.undead.increment();
+        let mut undead = Fighter::for_test(undead, RuleSet::default());
+        poisoner.afflict(AttackSource::Primary, &mut undead, 5);
+        assert!(undead.afflictions.is_empty());
+    }
+
+    #[test]
+    fn test_poison_wounds_extra() {
+        let mut extra = Character::default();
+        extra.experience.wild_card.decrement();
+        let rules = RuleSet {
+            extras_die_at_first_wound: true,
+            ..RuleSet::default()
+        };
+        let mut fighter = Fighter::for_test(extra, rules);
+        fighter.afflictions.poison = 1;
+
+        fighter.suffer_afflictions();
+
+        assert!(fighter.wounded);
+        assert!(fighter.is_dead());
+    }
+}
@@ -0,0 +1,215 @@
+//! Spellcasting of a fighter.
+//!
+//! Casting a spell takes the whole action. The fighter picks the first useful spell it knows
+//! and can pay for, otherwise it fights as usual.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::character::Spell;
+use crate::simulator::roller::{RollError, RollResult, roller};
+
+use super::Fighter;
+use super::modifiers::{Duration, Source, Target};
+
+/// how long the effects of a spell last
+const SPELL_DURATION: Duration = Duration::Rounds(5);
+
+impl Fighter {
+    /// returns `true` if a spell was cast, which uses up the action
+    pub(super) fn cast_spell(&mut self, opponents: &[Rc<RefCell<Fighter>>]) -> bool {
+        if self.power_points == 0 {
+            return false;
+        }
+        let Ok(mut opponent) = self.pick_opponent(opponents) else {
+            return false;
+        };
+        let Some(spell) = self.choose_spell(&opponent) else {
+            return false;
+        };
+
+        let mut roll = match roller().roll_skill_with_n_dice(
+            self.character.skills.zaubern,
+            1,
+            false,
+            self.wild_card(),
+        ) {
+            Ok(rolls) => rolls[0],
+            Err(RollError::CriticalFail) => {
+                self.backlash(spell);
+                return true;
+            }
+            Err(RollError::Fail) => unreachable!("a single 1 is no fail"),
+        };
+        self.apply_wound_penalty(&mut roll);
+        self.apply_joker(&mut roll);
+        self.apply_timed_modifiers(Target::Rolls, &mut roll);
+
+        let raise = match roll.eval() {
+            RollResult::Fail => {
+                // a failed spell still costs a power point
+                self.power_points -= 1;
+                return true;
+            }
+            RollResult::Success => false,
+            RollResult::Raise => true,
+        };
+        self.power_points -= spell.cost();
+        self.apply_spell(spell, raise, &mut opponent);
+        true
+    }
+
+    fn can_cast(&self, spell: Spell) -> bool {
+        self.character.arcane.knows(spell) && self.power_points >= spell.cost()
+    }
+
+    fn needs_healing(&self) -> bool {
+        self.passive_stats.life < self.max_life / 2
+    }
+
+    /// a bolt is only worth it if we can't fight better in melee
+    fn prefers_bolt(&self, opponent: &Self) -> bool {
+        if !self.character.weapon.active || self.weapon_lost {
+            return true;
+        }
+        if self.character.skills.zaubern > self.character.skills.kampfen {
+            return true;
+        }
+        !self.distance_map.borrow().base_contact(self, opponent)
+    }
+
+    fn choose_spell(&self, opponent: &Self) -> Option<Spell> {
+        let wanted = [
+            (Spell::Heal, self.needs_healing()),
+            (
+                Spell::Armor,
+                !self.modifiers.has(Source::Spell(Spell::Armor)),
+            ),
+            (
+                Spell::Boost,
+                !self.modifiers.has(Source::Spell(Spell::Boost)),
+            ),
+            (
+                Spell::Lower,
+                !opponent.modifiers.has(Source::Spell(Spell::Lower)),
+            ),
+            (Spell::Stun, !opponent.shaken),
+            (Spell::Bolt, self.prefers_bolt(opponent)),
+        ];
+        wanted
+            .into_iter()
+            .find(|(spell, wanted)| *wanted && self.can_cast(*spell))
+            .map(|(spell, _wanted)| spell)
+    }
+
+    /// critical fail: the caster is shaken and loses all active spells
+    fn backlash(&mut self, spell: Spell) {
+        self.power_points -= spell.cost();
+        self.modifiers.clear_spells();
+        self.shaken = true;
+    }
+
+    fn apply_spell(&mut self, spell: Spell, raise: bool, opponent: &mut Self) {
+        match spell {
+            Spell::Bolt => self.cast_bolt(raise, opponent),
+            Spell::Armor => {
+                let value = if raise { 4 } else { 2 };
+                let source = Source::Spell(spell);
+                self.modifiers
+                    .add(source, Target::Armor, value, SPELL_DURATION);
+            }
+            Spell::Boost => {
+                let value = if raise { 2 } else { 1 };
+                let source = Source::Spell(spell);
+                self.modifiers
+                    .add(source, Target::Attack, value, SPELL_DURATION);
+            }
+            Spell::Lower => {
+                let value = if raise { -2 } else { -1 };
+                let source = Source::Spell(spell);
+                opponent
+                    .modifiers
+                    .add(source, Target::Attack, value, SPELL_DURATION);
+                opponent
+                    .modifiers
+                    .add(source, Target::Parry, value, SPELL_DURATION);
+            }
+            Spell::Heal => self.cast_heal(raise),
+            Spell::Stun => Self::cast_stun(raise, opponent),
+        }
+    }
+
+    fn cast_bolt(&mut self, raise: bool, opponent: &mut Self) {
+        if let Some(stats) = self.fight_stats.as_ref() {
+            stats.borrow_mut().add_hits_dealt(1);
+        }
+        if let Some(stats) = opponent.fight_stats.as_ref() {
+            stats.borrow_mut().add_hits_received(1);
+        }
+
+        let damage = roller().roll_d6s(if raise { 3 } else { 2 });
+        if u8::from(damage) >= opponent.robustness() {
+            self.inflict_damage(opponent, damage);
+        }
+    }
+
+    fn cast_heal(&mut self, raise: bool) {
+        let healing = roller().roll_d6s(if raise { 3 } else { 2 });
+        let healed = self.receive_healing(healing.as_u8());
+        self.add_healing_stats(healed);
+    }
+
+    fn cast_stun(raise: bool, opponent: &mut Self) {
+        let roll = roller().roll_attribute(opponent.character.attributes.kon, opponent.wild_card());
+        let resisted = match roll {
+            Ok(mut roll) => {
+                if raise {
+                    roll -= 2_u8;
+                }
+                roll.eval() != RollResult::Fail
+            }
+            Err(_) => false,
+        };
+        if resisted {
+            return;
+        }
+        opponent.shaken = true;
+        if raise {
+            // distracted until the end of its next turn
+            let source = Source::Spell(Spell::Stun);
+            opponent
+                .modifiers
+                .add(source, Target::Rolls, -2, Duration::Turns(1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_spells_are_chosen_by_need_and_power_points() {
+        let mut mage = Character::default();
+        mage.weapon.active = false;
+        mage.arcane.power_points.set(2);
+        mage.arcane.armor.increment();
+        mage.arcane.bolt.increment();
+        let mut mage = Fighter::for_test(mage, RuleSet::default());
+        let opponent = Fighter::for_test(Character::default(), RuleSet::default());
+
+        assert_eq!(mage.choose_spell(&opponent), Some(Spell::Armor));
+
+        // the armor is already up
+        let source = Source::Spell(Spell::Armor);
+        mage.modifiers.add(source, Target::Armor, 2, SPELL_DURATION);
+        assert_eq!(mage.choose_spell(&opponent), Some(Spell::Bolt));
+
+        mage.power_points = 0;
+        assert_eq!(mage.choose_spell(&opponent), None);
+    }
+}
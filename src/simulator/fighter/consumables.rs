@@ -0,0 +1,160 @@
+//! Consumables a fighter uses during a fight.
+//!
+//! Drinking a strength elixir doesn't cost an action, throwing a knife does.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::character::{Consumable, Consumables};
+use crate::simulator::roller::{RollResult, roller};
+
+use super::Fighter;
+use super::modifiers::{Duration, Source, Target};
+
+/// Consumables a fighter still carries
+#[derive(Debug, Clone)]
+pub struct Supplies {
+    healing_potions: u8,
+    strength_elixirs: u8,
+    throwing_knives: u8,
+}
+
+impl Supplies {
+    pub fn new(consumables: &Consumables) -> Self {
+        Self {
+            healing_potions: consumables.count(Consumable::HealingPotion),
+            strength_elixirs: consumables.count(Consumable::StrengthElixir),
+            throwing_knives: consumables.count(Consumable::ThrowingKnife),
+        }
+    }
+
+    fn count_mut(&mut self, consumable: Consumable) -> &mut u8 {
+        match consumable {
+            Consumable::HealingPotion => &mut self.healing_potions,
+            Consumable::StrengthElixir => &mut self.strength_elixirs,
+            Consumable::ThrowingKnife => &mut self.throwing_knives,
+        }
+    }
+
+    pub fn has(&self, consumable: Consumable) -> bool {
+        match consumable {
+            Consumable::HealingPotion => self.healing_potions > 0,
+            Consumable::StrengthElixir => self.strength_elixirs > 0,
+            Consumable::ThrowingKnife => self.throwing_knives > 0,
+        }
+    }
+}
+
+impl Fighter {
+    /// returns `false` if there is nothing left
+    pub(super) fn use_consumable(&mut self, consumable: Consumable) -> bool {
+        let count = self.supplies.count_mut(consumable);
+        if *count == 0 {
+            return false;
+        }
+        *count -= 1;
+        if let Some(stats) = self.fight_stats.as_ref() {
+            stats.borrow_mut().add_consumable_used();
+        }
+        true
+    }
+
+    /// drinking doesn't use up the action
+    pub(super) fn drink_elixir(&mut self) {
+        let source = Source::Consumable(Consumable::StrengthElixir);
+        if self.modifiers.has(source) {
+            return;
+        }
+        if self.passive_stats.life >= self.character.tactics.elixir_threshold() {
+            return;
+        }
+        if !self.use_consumable(Consumable::StrengthElixir) {
+            return;
+        }
+        self.modifiers
+            .add(source, Target::Damage, 2, Duration::Rounds(5));
+    }
+
+    /// returns `true` if a knife was thrown, which uses up the action
+    pub(super) fn throw_knife(&mut self, opponents: &[Rc<RefCell<Fighter>>]) -> bool {
+        if !self.character.tactics.throw_knives.is_set()
+            || !self.supplies.has(Consumable::ThrowingKnife)
+        {
+            return false;
+        }
+        let Ok(mut opponent) = self.pick_opponent(opponents) else {
+            return false;
+        };
+        if self.distance_map.borrow().base_contact(self, &opponent) {
+            return false;
+        }
+        self.use_consumable(Consumable::ThrowingKnife);
+
+        let Ok(mut roll) = roller().roll_attribute(self.character.attributes.ges, self.wild_card())
+        else {
+            // no crit fail table for throwing, it's just a miss
+            return true;
+        };
+        self.apply_wound_penalty(&mut roll);
+        self.apply_joker(&mut roll);
+        self.apply_timed_modifiers(Target::Attack, &mut roll);
+        self.apply_arm_wounds(&mut roll);
+        roll += self.scale_modifier(&opponent);
+        Self::apply_the_drop(&opponent, &mut roll);
+        self.apply_environment(true, &mut roll);
+        let raise = match roll.eval() {
+            RollResult::Fail => return true,
+            RollResult::Success => false,
+            RollResult::Raise => true,
+        };
+
+        if let Some(stats) = self.fight_stats.as_ref() {
+            stats.borrow_mut().add_hits_dealt(1);
+        }
+        if let Some(stats) = opponent.fight_stats.as_ref() {
+            stats.borrow_mut().add_hits_received(1);
+        }
+
+        let mut damage = roller().roll_attribute_without_wild_die(self.character.attributes.sta);
+        damage += roller().roll_d4();
+        if raise {
+            damage += roller().roll_raise();
+        }
+        damage += self.modifiers.get(Target::Damage);
+        Self::apply_the_drop(&opponent, &mut damage);
+        self.apply_joker_to_damage(&mut damage);
+        if u8::from(damage) >= opponent.robustness() {
+            self.inflict_damage(&mut opponent, damage);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_elixir_is_drunk_below_threshold() {
+        let mut drinker = Character::default();
+        drinker.consumables.strength_elixirs.set(2);
+        drinker.tactics.elixir_threshold.set(10);
+        let mut drinker = Fighter::for_test(drinker, RuleSet::default());
+
+        drinker.drink_elixir();
+        assert_eq!(drinker.modifiers.get(Target::Damage), 0);
+
+        drinker.passive_stats.life = 9;
+        drinker.drink_elixir();
+        assert_eq!(drinker.modifiers.get(Target::Damage), 2);
+
+        // the effect doesn't stack
+        drinker.drink_elixir();
+        assert_eq!(drinker.modifiers.get(Target::Damage), 2);
+        assert!(drinker.supplies.has(Consumable::StrengthElixir));
+    }
+}
@@ -0,0 +1,27 @@
+//! Effects of the place where the fight happens.
+//!
+//! The environment is part of the scenario and the same for all fighters.
+
+use crate::simulator::roller::Roll;
+
+use super::Fighter;
+
+impl Fighter {
+    /// penalty of light and weather to an attack roll
+    pub(super) fn apply_environment(&self, ranged: bool, roll: &mut Roll) {
+        *roll -= self.environment.lighting_penalty();
+        if ranged {
+            *roll -= self.environment.ranged_penalty();
+        }
+    }
+
+    /// moving to the next opponent takes the whole action
+    pub(super) fn slowed_down(&self) -> bool {
+        self.has_leg_wound() || self.environment.difficult_ground
+    }
+
+    /// there's no room for more attackers or wide swings
+    pub(super) fn cramped(&self) -> bool {
+        self.environment.narrow
+    }
+}
@@ -0,0 +1,79 @@
+//! Front and back rank of a group.
+//!
+//! Opponents always go for the front rank first. Fighters in the back rank only attack with reach
+//! weapons over the shoulders of their allies, until the front rank has fallen.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::Fighter;
+
+impl Fighter {
+    pub fn in_back_rank(&self) -> bool {
+        self.character.tactics.back_rank.is_set()
+    }
+
+    /// the opponent everybody attacks: the first one in the front rank, if any is left
+    ///
+    /// Bodyguards intercept attacks on their wards.
+    pub fn target(opponents: &[Rc<RefCell<Fighter>>]) -> Option<&Rc<RefCell<Fighter>>> {
+        let target = opponents
+            .iter()
+            .find(|opponent| !opponent.borrow().in_back_rank())
+            .or_else(|| opponents.first())?;
+        Some(Self::intercept(opponents, target))
+    }
+
+    /// returns `true` if we stand in the back rank and an ally still stands in front of us
+    pub(super) fn covered(&self, allies: &[Rc<RefCell<Fighter>>]) -> bool {
+        self.in_back_rank()
+            && self.other_allies(allies).any(|ally| {
+                let ally = ally.borrow();
+                !ally.in_back_rank() && !ally.is_dead()
+            })
+    }
+
+    /// attack over the shoulders of the front rank, which only works with a reach weapon
+    pub(super) fn attack_from_back_rank(&mut self, opponents: &[Rc<RefCell<Fighter>>]) {
+        if !self.character.weapon.active || !self.weapon_has_reach() {
+            return;
+        }
+        let Ok(mut opponent) = self.pick_opponent(opponents) else {
+            return;
+        };
+        self.distance_map
+            .borrow_mut()
+            .engage_at_reach(self, &opponent);
+        self.attack_with_primary_weapon(&mut opponent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+
+    use super::*;
+
+    #[test]
+    fn test_back_rank_is_covered_by_front_rank() {
+        let mut back_rank = Character::default();
+        back_rank.tactics.back_rank.increment();
+        let fighter = Fighter::for_test(back_rank.clone(), RuleSet::default());
+        let behind = fighter.opponent_for_test(back_rank);
+        let front = fighter.opponent_for_test(Character::default());
+        assert!(fighter.in_back_rank());
+
+        let group = [Rc::clone(&behind), Rc::clone(&front)];
+        assert!(fighter.covered(&group));
+        let target = Fighter::target(&group).unwrap();
+        assert!(Rc::ptr_eq(target, &front));
+
+        // without a front rank, the back rank is attacked
+        front.borrow_mut().passive_stats.life = 0;
+        assert!(!fighter.covered(&group));
+        let group = [Rc::clone(&behind)];
+        let target = Fighter::target(&group).unwrap();
+        assert!(Rc::ptr_eq(target, &behind));
+    }
+}
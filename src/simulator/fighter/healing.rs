@@ -0,0 +1,128 @@
+//! Healing of wounded allies during a fight.
+//!
+//! Healing takes the whole action. A trained healer uses Heilkunde Wunden, everybody else falls
+//! back to healing potions.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::character::{Consumable, Skill};
+use crate::simulator::roller::{RollError, RollResult, roller};
+
+use super::Fighter;
+use super::modifiers::Target;
+
+impl Fighter {
+    /// returns `true` if we tried to heal somebody, which uses up the action
+    pub(super) fn heal(&mut self, allies: &[Rc<RefCell<Fighter>>]) -> bool {
+        if !self.can_heal() {
+            return false;
+        }
+        let threshold = self.character.tactics.heal_threshold();
+        let ally = self
+            .other_allies(allies)
+            .filter(|ally| !ally.borrow().is_dead())
+            .filter(|ally| ally.borrow().passive_stats.life < threshold)
+            .min_by_key(|ally| ally.borrow().passive_stats.life);
+        let own_life = self.passive_stats.life;
+
+        match ally {
+            Some(ally) if own_life >= threshold || ally.borrow().passive_stats.life < own_life => {
+                let healing = self.roll_healing();
+                let healed = ally.borrow_mut().receive_healing(healing);
+                self.add_healing_stats(healed);
+            }
+            _ if own_life < threshold => {
+                let healing = self.roll_healing();
+                let healed = self.receive_healing(healing);
+                self.add_healing_stats(healed);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn trained_healer(&self) -> bool {
+        self.character.skills.heilkunde > Skill::W4m2
+    }
+
+    fn can_heal(&self) -> bool {
+        self.trained_healer() || self.supplies.has(Consumable::HealingPotion)
+    }
+
+    /// roll the amount of life restored, a failed attempt restores nothing
+    fn roll_healing(&mut self) -> u8 {
+        if !self.trained_healer() {
+            self.use_consumable(Consumable::HealingPotion);
+            return roller().roll_d6s(2).as_u8();
+        }
+
+        let mut roll = match roller().roll_skill_with_n_dice(
+            self.character.skills.heilkunde,
+            1,
+            false,
+            self.wild_card(),
+        ) {
+            Ok(rolls) => rolls[0],
+            Err(RollError::CriticalFail) => return 0,
+            Err(RollError::Fail) => unreachable!("a single 1 is no fail"),
+        };
+        self.apply_wound_penalty(&mut roll);
+        self.apply_joker(&mut roll);
+        self.apply_timed_modifiers(Target::Rolls, &mut roll);
+
+        match roll.eval() {
+            RollResult::Fail => 0,
+            RollResult::Success => roller().roll_d6s(1).as_u8(),
+            RollResult::Raise => roller().roll_d6s(2).as_u8(),
+        }
+    }
+
+    /// returns the life that was actually restored
+    pub(super) fn receive_healing(&mut self, healing: u8) -> u8 {
+        let life = self.passive_stats.life.saturating_add(healing);
+        let life = life.min(self.max_life);
+        let healed = life - self.passive_stats.life;
+        self.passive_stats.life = life;
+        healed
+    }
+
+    pub(super) fn add_healing_stats(&self, healed: u8) {
+        if let Some(stats) = self.fight_stats.as_ref() {
+            stats.borrow_mut().add_healing(healed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_potion_heals_the_weakest_ally() {
+        let mut healer = Character::default();
+        healer.consumables.healing_potions.set(1);
+        healer.tactics.heal_threshold.set(20);
+        let mut healer = Fighter::for_test(healer, RuleSet::default());
+        let ally = Fighter::for_test(Character::default(), RuleSet::default());
+        let max_life = ally.max_life;
+        let ally = Rc::new(RefCell::new(ally));
+        let allies = [Rc::clone(&ally)];
+
+        // nobody is below the threshold
+        assert!(!healer.heal(&allies));
+
+        ally.borrow_mut().passive_stats.life = 10;
+        roller().seed(1);
+        let healing = roller().roll_d6s(2).as_u8();
+        roller().seed(1);
+        assert!(healer.heal(&allies));
+        let life = ally.borrow().passive_stats.life;
+        assert_eq!(life, max_life.min(10 + healing));
+        assert!(!healer.supplies.has(Consumable::HealingPotion));
+    }
+}
@@ -0,0 +1,86 @@
+//! Leadership edges, which don't affect their owner but the allies of its group.
+//!
+//! The arena collects the auras of all leaders of a group at the start of each round and hands
+//! each fighter the best aura of its allies.
+
+use crate::app::character::Aura;
+use crate::simulator::roller::{RollResult, roller};
+
+use super::Fighter;
+
+impl Fighter {
+    /// has a leadership edge
+    pub fn is_leader(&self) -> bool {
+        self.character.edges.has_hook(|hooks| hooks.aura)
+    }
+
+    /// the aura this fighter grants its allies
+    pub fn leader_aura(&self) -> Aura {
+        if self.character.edges.is_empty() {
+            return Aura::default();
+        }
+        self.character.edges.aura()
+    }
+
+    /// set by the arena, the aura of the leaders among our allies
+    pub fn follow(&mut self, aura: Aura) {
+        self.aura = aura;
+    }
+
+    pub fn is_shaken(&self) -> bool {
+        self.shaken
+    }
+
+    /// free action of a leader to unshake all allies, returns `true` on success
+    pub fn inspire(&self) -> bool {
+        let Ok(mut roll) = roller().roll_attribute(self.character.attributes.wil, self.wild_card())
+        else {
+            return false;
+        };
+        self.apply_wound_penalty(&mut roll);
+        self.apply_joker(&mut roll);
+        let success = roll.eval() != RollResult::Fail;
+        if success {
+            log::trace!("{} inspires the group", self.character.name.as_str());
+        }
+        success
+    }
+
+    pub fn be_inspired(&mut self) {
+        self.shaken = false;
+    }
+
+    /// robustness including the aura of our leaders
+    pub(super) fn robustness(&self) -> u8 {
+        self.passive_stats
+            .robustness
+            .saturating_add_signed(self.aura.robustness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::{Character, EdgeId};
+    use crate::app::rules::RuleSet;
+
+    use super::*;
+
+    #[test]
+    fn test_leader_strengthens_followers() {
+        let mut extra = Character::default();
+        extra.experience.wild_card.decrement();
+        let mut leader = extra.clone();
+        leader.edges.increment(EdgeId::Kommandieren);
+        leader.edges.increment(EdgeId::HaltDieLinie);
+        let leader = Fighter::for_test(leader, RuleSet::default());
+        let mut follower = Fighter::for_test(extra, RuleSet::default());
+        assert!(leader.is_leader());
+        assert!(!follower.is_leader());
+
+        let robustness = follower.robustness();
+        follower.follow(leader.leader_aura());
+        assert_eq!(follower.robustness(), robustness + 1);
+        follower.follow(Aura::default());
+        assert_eq!(follower.robustness(), robustness);
+    }
+}
@@ -0,0 +1,165 @@
+//! Modifiers of a fighter that only last for a limited time, e.g. the effects of spells or the
+//! rage of a berserker.
+
+use crate::app::character::{Consumable, Spell};
+
+/// What is modified
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// attack rolls
+    Attack,
+    Parry,
+    Armor,
+    Damage,
+    /// all trait rolls, including attacks
+    Rolls,
+}
+
+/// Where a modifier comes from, the same source can't stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Spell(Spell),
+    Consumable(Consumable),
+    /// raging berserker
+    Rage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duration {
+    /// ends after the given number of rounds
+    Rounds(u8),
+    /// ends after the owner finished the given number of turns
+    Turns(u8),
+    /// lasts until the end of the fight
+    Fight,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TimedModifier {
+    source: Source,
+    target: Target,
+    value: i8,
+    duration: Duration,
+}
+
+impl TimedModifier {
+    fn is_over(self) -> bool {
+        matches!(self.duration, Duration::Rounds(0) | Duration::Turns(0))
+    }
+}
+
+/// All timed modifiers that are currently active on a fighter
+#[derive(Debug, Default, Clone)]
+pub struct TimedModifiers {
+    modifiers: Vec<TimedModifier>,
+}
+
+impl TimedModifiers {
+    /// add a modifier, replacing the one from the same source for the same target
+    pub fn add(&mut self, source: Source, target: Target, value: i8, duration: Duration) {
+        self.modifiers
+            .retain(|m| m.source != source || m.target != target);
+        self.modifiers.push(TimedModifier {
+            source,
+            target,
+            value,
+            duration,
+        });
+    }
+
+    pub fn has(&self, source: Source) -> bool {
+        self.modifiers.iter().any(|m| m.source == source)
+    }
+
+    /// sum of all modifiers for the given target
+    pub fn get(&self, target: Target) -> i8 {
+        let mut sum = 0;
+        for modifier in &self.modifiers {
+            if modifier.target == target {
+                sum += modifier.value;
+            }
+        }
+        sum
+    }
+
+    /// end all spells, other effects stay
+    pub fn clear_spells(&mut self) {
+        self.modifiers
+            .retain(|m| !matches!(m.source, Source::Spell(_)));
+    }
+
+    /// called at the start of each round
+    pub fn tick_round(&mut self) {
+        for modifier in &mut self.modifiers {
+            if let Duration::Rounds(rounds) = &mut modifier.duration {
+                *rounds = rounds.saturating_sub(1);
+            }
+        }
+        self.modifiers.retain(|m| !m.is_over());
+    }
+
+    /// called at the end of each turn of the owner
+    pub fn tick_turn(&mut self) {
+        for modifier in &mut self.modifiers {
+            if let Duration::Turns(turns) = &mut modifier.duration {
+                *turns = turns.saturating_sub(1);
+            }
+        }
+        self.modifiers.retain(|m| !m.is_over());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifiers_expire() {
+        let mut modifiers = TimedModifiers::default();
+        let armor = Source::Spell(Spell::Armor);
+        let stun = Source::Spell(Spell::Stun);
+        modifiers.add(armor, Target::Armor, 2, Duration::Rounds(2));
+        modifiers.add(stun, Target::Rolls, -2, Duration::Turns(1));
+        assert_eq!(modifiers.get(Target::Armor), 2);
+        assert_eq!(modifiers.get(Target::Rolls), -2);
+
+        modifiers.tick_round();
+        assert_eq!(modifiers.get(Target::Armor), 2);
+        assert_eq!(modifiers.get(Target::Rolls), -2);
+
+        modifiers.tick_turn();
+        assert_eq!(modifiers.get(Target::Rolls), 0);
+        assert!(!modifiers.has(stun));
+
+        modifiers.tick_round();
+        assert_eq!(modifiers.get(Target::Armor), 0);
+        assert!(!modifiers.has(armor));
+    }
+
+    #[test]
+    fn test_same_source_does_not_stack() {
+        let mut modifiers = TimedModifiers::default();
+        let boost = Source::Spell(Spell::Boost);
+        modifiers.add(boost, Target::Attack, 1, Duration::Rounds(5));
+        modifiers.add(boost, Target::Attack, 2, Duration::Rounds(5));
+        assert_eq!(modifiers.get(Target::Attack), 2);
+    }
+
+    #[test]
+    fn test_rage_outlasts_spells() {
+        let mut modifiers = TimedModifiers::default();
+        let boost = Source::Spell(Spell::Boost);
+        modifiers.add(Source::Rage, Target::Attack, 2, Duration::Fight);
+        modifiers.add(boost, Target::Attack, 1, Duration::Rounds(5));
+        for _ in 0..10 {
+            modifiers.tick_round();
+            modifiers.tick_turn();
+        }
+        assert_eq!(modifiers.get(Target::Attack), 2);
+
+        modifiers.add(boost, Target::Attack, 1, Duration::Rounds(5));
+        modifiers.clear_spells();
+        assert!(modifiers.has(Source::Rage));
+        assert!(!modifiers.has(boost));
+    }
+}
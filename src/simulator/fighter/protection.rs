@@ -0,0 +1,108 @@
+//! Bodyguards protecting the wards of their group.
+//!
+//! An intercepting bodyguard takes the attacks meant for a ward, an engaging bodyguard goes for
+//! whoever is in melee with a ward.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::character::Protection;
+
+use super::Fighter;
+
+impl Fighter {
+    pub fn is_ward(&self) -> bool {
+        self.character.tactics.ward.is_set()
+    }
+
+    fn intercepts(&self) -> bool {
+        self.character.tactics.protection == Protection::Intercept
+            && !self.is_ward()
+            && !self.is_dead()
+    }
+
+    /// a bodyguard steps in if the target is a ward
+    pub(super) fn intercept<'o>(
+        opponents: &'o [Rc<RefCell<Fighter>>],
+        target: &'o Rc<RefCell<Fighter>>,
+    ) -> &'o Rc<RefCell<Fighter>> {
+        if !target.borrow().is_ward() {
+            return target;
+        }
+        opponents
+            .iter()
+            .find(|opponent| opponent.borrow().intercepts())
+            .unwrap_or(target)
+    }
+
+    /// the opponent in melee with one of our wards, if we are supposed to engage it
+    pub(super) fn attacker_of_ward<'o>(
+        &self,
+        opponents: &'o [Rc<RefCell<Fighter>>],
+        allies: &[Rc<RefCell<Fighter>>],
+    ) -> Option<&'o Rc<RefCell<Fighter>>> {
+        if self.character.tactics.protection != Protection::Engage {
+            return None;
+        }
+        let distance_map = self.distance_map.borrow();
+        self.other_allies(allies)
+            .map(|ally| ally.borrow())
+            .filter(|ally| ally.is_ward() && !ally.is_dead())
+            .find_map(|ward| {
+                opponents.iter().find(|opponent| {
+                    let opponent = opponent.borrow();
+                    !opponent.is_dead() && distance_map.engaged(&ward, &opponent)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+
+    use super::*;
+
+    #[test]
+    fn test_bodyguard_intercepts_attacks_on_ward() {
+        let mut ward = Character::default();
+        ward.tactics.ward.increment();
+        let mut knight = Character::default();
+        knight.tactics.protection = Protection::Intercept;
+        let opponent = Fighter::for_test(Character::default(), RuleSet::default());
+        let ward = opponent.opponent_for_test(ward);
+        let knight = opponent.opponent_for_test(knight);
+        let group = [Rc::clone(&ward), Rc::clone(&knight)];
+
+        let target = Fighter::target(&group).unwrap();
+        assert!(Rc::ptr_eq(target, &knight));
+
+        knight.borrow_mut().passive_stats.life = 0;
+        let target = Fighter::target(&group).unwrap();
+        assert!(Rc::ptr_eq(target, &ward));
+    }
+
+    #[test]
+    fn test_bodyguard_engages_attacker_of_ward() {
+        let mut ward = Character::default();
+        ward.tactics.ward.increment();
+        let mut knight = Character::default();
+        knight.tactics.protection = Protection::Engage;
+        let knight = Fighter::for_test(knight, RuleSet::default());
+        let ward = Rc::new(RefCell::new(Fighter::for_test(ward, RuleSet::default())));
+        let attacker = knight.opponent_for_test(Character::default());
+        let bystander = knight.opponent_for_test(Character::default());
+        let opponents = [Rc::clone(&bystander), Rc::clone(&attacker)];
+        let allies = [Rc::clone(&ward)];
+
+        assert!(knight.attacker_of_ward(&opponents, &allies).is_none());
+
+        knight
+            .distance_map
+            .borrow_mut()
+            .set_base_contact(&ward.borrow(), &attacker.borrow(), true);
+        let target = knight.attacker_of_ward(&opponents, &allies).unwrap();
+        assert!(Rc::ptr_eq(target, &attacker));
+    }
+}
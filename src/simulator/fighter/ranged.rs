@@ -0,0 +1,141 @@
+//! Ranged combat of a fighter.
+//!
+//! A fighter with a ranged weapon shoots as long as its target isn't in melee and there is ammo
+//! left. Reloading takes actions, after that the fighter switches to melee.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::rules::Fumble;
+use crate::simulator::roller::{RollError, RollResult, roller};
+
+use super::modifiers::Target;
+use super::{Fighter, roll_fumble};
+
+impl Fighter {
+    /// returns `true` if we shot or reloaded, which uses up the action
+    pub(super) fn shoot(&mut self, opponents: &[Rc<RefCell<Fighter>>]) -> bool {
+        if self.ammo == 0 {
+            return false;
+        }
+        let Ok(mut opponent) = self.pick_opponent(opponents) else {
+            return false;
+        };
+        if self.distance_map.borrow().base_contact(self, &opponent) {
+            return false;
+        }
+        if self.reloading > 0 {
+            self.reloading -= 1;
+            return true;
+        }
+
+        self.ammo -= 1;
+        self.reloading = self.character.ranged_weapon.reload_actions();
+
+        let mut roll = match roller().roll_skill_with_n_dice(
+            self.character.skills.schiessen,
+            1,
+            false,
+            self.wild_card(),
+        ) {
+            Ok(rolls) => rolls[0],
+            Err(RollError::CriticalFail) => {
+                self.ranged_critical_fail();
+                return true;
+            }
+            Err(RollError::Fail) => unreachable!("a single 1 is no fail"),
+        };
+        self.apply_wound_penalty(&mut roll);
+        self.apply_joker(&mut roll);
+        self.apply_timed_modifiers(Target::Attack, &mut roll);
+        self.apply_arm_wounds(&mut roll);
+        roll += self.scale_modifier(&opponent);
+        Self::apply_the_drop(&opponent, &mut roll);
+        self.apply_environment(true, &mut roll);
+        let raise = match roll.eval() {
+            RollResult::Fail => return true,
+            RollResult::Success => false,
+            RollResult::Raise => true,
+        };
+
+        if let Some(stats) = self.fight_stats.as_ref() {
+            stats.borrow_mut().add_hits_dealt(1);
+        }
+        if let Some(stats) = opponent.fight_stats.as_ref() {
+            stats.borrow_mut().add_hits_received(1);
+        }
+
+        let weapon = &self.character.ranged_weapon;
+        let mut damage = roller().roll_ranged_damage(weapon);
+        if raise {
+            damage += roller().roll_raise();
+        }
+        let piercing = i8::from(weapon.piercing);
+        damage += piercing.min(i8::from(opponent.character.armor.torso));
+        damage += self.modifiers.get(Target::Damage);
+        Self::apply_the_drop(&opponent, &mut damage);
+        self.apply_joker_to_damage(&mut damage);
+        if u8::from(damage) >= opponent.robustness() {
+            self.inflict_damage(&mut opponent, damage);
+        }
+        true
+    }
+
+    fn ranged_critical_fail(&mut self) {
+        match roll_fumble(&self.rules.fumble_tables.ranged) {
+            Fumble::Nothing => (),
+            Fumble::WeaponDestroyed | Fumble::WeaponBroken | Fumble::WeaponLost => {
+                // switch to melee
+                self.ammo = 0;
+            }
+            Fumble::Shaken | Fumble::Injured | Fumble::HeavilyInjured => self.shaken = true,
+            Fumble::Fell | Fumble::Tripped => {
+                self.shaken = true;
+                self.fell = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+    use crate::app::widgets::ValueSlider as _;
+
+    use super::*;
+
+    #[test]
+    fn test_shooting_uses_ammo_and_reloads() {
+        // no critical fail that loses the weapon
+        roller().seed(2);
+        let mut archer = Character::default();
+        archer.ranged_weapon.damage.increment();
+        archer.ranged_weapon.ammo.set(2);
+        archer.ranged_weapon.reload.set(1);
+        let mut archer = Fighter::for_test(archer, RuleSet::default());
+        let opponent = archer.opponent_for_test(Character::default());
+        let opponents = [Rc::clone(&opponent)];
+
+        // no shooting in melee
+        archer
+            .distance_map
+            .borrow_mut()
+            .set_base_contact(&archer, &opponent.borrow(), true);
+        assert!(!archer.shoot(&opponents));
+        archer
+            .distance_map
+            .borrow_mut()
+            .set_base_contact(&archer, &opponent.borrow(), false);
+
+        archer.reloading = 1;
+        assert!(archer.shoot(&opponents));
+        assert_eq!((archer.ammo, archer.reloading), (2, 0));
+
+        assert!(archer.shoot(&opponents));
+        assert_eq!((archer.ammo, archer.reloading), (1, 1));
+
+        archer.ammo = 0;
+        assert!(!archer.shoot(&opponents));
+    }
+}
@@ -0,0 +1,72 @@
+//! Surprise at the start of an ambush.
+//!
+//! Surprised fighters don't get a card in the first round, and everybody attacking them has the
+//! drop on them.
+
+use crate::simulator::roller::{Roll, RollResult, roller};
+
+use super::Fighter;
+
+/// bonus to attack and damage against surprised fighters
+const THE_DROP: u8 = 4;
+
+impl Fighter {
+    /// called before the fight, `notice_roll` gives a chance to spot the ambush
+    pub fn be_ambushed(&mut self, notice_roll: bool) {
+        self.surprised = !notice_roll || !self.notices_ambush();
+        if self.surprised {
+            log::trace!("{} is surprised", self.character.name.as_str());
+        }
+    }
+
+    fn notices_ambush(&self) -> bool {
+        let Ok(rolls) = roller().roll_skill_with_n_dice(
+            self.character.skills.wahrnehmung,
+            1,
+            false,
+            self.wild_card(),
+        ) else {
+            return false;
+        };
+        rolls[0].eval() != RollResult::Fail
+    }
+
+    pub fn is_surprised(&self) -> bool {
+        self.surprised
+    }
+
+    /// called after the first round
+    pub fn recover_from_surprise(&mut self) {
+        self.surprised = false;
+    }
+
+    /// for attack and damage rolls
+    pub(super) fn apply_the_drop(opponent: &Self, roll: &mut Roll) {
+        if opponent.surprised {
+            *roll += THE_DROP;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+
+    use super::*;
+
+    #[test]
+    fn test_the_drop_on_surprised_fighters() {
+        let mut fighter = Fighter::for_test(Character::default(), RuleSet::default());
+        fighter.be_ambushed(false);
+        assert!(fighter.is_surprised());
+        let mut roll = roller().roll_d6s(0);
+        Fighter::apply_the_drop(&fighter, &mut roll);
+        assert_eq!(roll.as_i8(), 4);
+
+        fighter.recover_from_surprise();
+        let mut roll = roller().roll_d6s(0);
+        Fighter::apply_the_drop(&fighter, &mut roll);
+        assert_eq!(roll.as_i8(), 0);
+    }
+}
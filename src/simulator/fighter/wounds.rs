@@ -0,0 +1,126 @@
+//! Wounds at hit locations, only used with [`WoundModel::Locations`].
+//!
+//! Each hit that deals more damage than the wound threshold (the sides of the Kon die) causes
+//! wounds at the hit location, which replace the flat penalty derived from the remaining life.
+
+use crate::app::rules::WoundModel;
+use crate::simulator::roller::{Roll, roller};
+
+use super::Fighter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Location {
+    Head,
+    Torso,
+    Arms,
+    Legs,
+}
+
+impl Location {
+    fn roll() -> Self {
+        match roller().roll_hit_location() {
+            1..=2 => Self::Head,
+            3..=10 => Self::Torso,
+            11..=15 => Self::Arms,
+            _ => Self::Legs,
+        }
+    }
+}
+
+/// Wounds per location a fighter suffers from
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Wounds {
+    /// worse initiative
+    head: u8,
+    /// penalty to all rolls
+    torso: u8,
+    /// penalty to attacks
+    arms: u8,
+    /// moving costs the action
+    legs: u8,
+}
+
+impl Fighter {
+    fn location_wounds(&self) -> Option<&Wounds> {
+        (self.rules.wound_model == WoundModel::Locations).then_some(&self.wounds)
+    }
+
+    /// called with the damage of each hit that got through, without an aimed location a random
+    /// one is hit
+    pub(super) fn receive_wounds(&mut self, damage: u8, aimed_at: Option<Location>) {
+        if self.rules.wound_model != WoundModel::Locations || damage == 0 {
+            return;
+        }
+        let threshold: u8 = self.character.attributes.kon.into();
+        let count = (damage - 1) / threshold;
+        if count == 0 {
+            return;
+        }
+        let location = aimed_at.unwrap_or_else(Location::roll);
+        let wounds = match location {
+            Location::Head => &mut self.wounds.head,
+            Location::Torso => &mut self.wounds.torso,
+            Location::Arms => &mut self.wounds.arms,
+            Location::Legs => &mut self.wounds.legs,
+        };
+        *wounds = wounds.saturating_add(count);
+    }
+
+    /// penalty to all rolls, replaces the penalty by remaining life
+    pub(super) fn torso_wound_penalty(&self) -> Option<u8> {
+        self.location_wounds().map(|wounds| wounds.torso)
+    }
+
+    pub(super) fn apply_arm_wounds(&self, roll: &mut Roll) {
+        if let Some(wounds) = self.location_wounds() {
+            *roll -= wounds.arms.saturating_mul(2);
+        }
+    }
+
+    /// number of extra cards drawn, of which the worst is taken
+    pub(super) fn head_wounds(&self) -> u8 {
+        self.location_wounds().map_or(0, |wounds| wounds.head)
+    }
+
+    pub(super) fn has_leg_wound(&self) -> bool {
+        self.location_wounds().is_some_and(|wounds| wounds.legs > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::character::Character;
+    use crate::app::rules::RuleSet;
+
+    use super::*;
+
+    #[test]
+    fn test_wounds_at_aimed_location() {
+        let rules = RuleSet {
+            wound_model: WoundModel::Locations,
+            ..RuleSet::default()
+        };
+        let mut fighter = Fighter::for_test(Character::default(), rules);
+        let threshold: u8 = fighter.character.attributes.kon.into();
+
+        fighter.receive_wounds(threshold, Some(Location::Arms));
+        assert_eq!(fighter.wounds.arms, 0);
+        fighter.receive_wounds(2 * threshold + 1, Some(Location::Legs));
+        assert_eq!(fighter.wounds.legs, 2);
+        assert!(fighter.has_leg_wound());
+        assert_eq!(fighter.torso_wound_penalty(), Some(0));
+
+        // the penalty saturates
+        fighter.wounds.arms = u8::MAX;
+        let mut roll = roller().roll_d6s(0);
+        fighter.apply_arm_wounds(&mut roll);
+        assert_eq!(roll.as_i8(), -i8::MAX);
+    }
+
+    #[test]
+    fn test_no_wounds_without_locations() {
+        let mut fighter = Fighter::for_test(Character::default(), RuleSet::default());
+        fighter.receive_wounds(u8::MAX, Some(Location::Torso));
+        assert_eq!(fighter.torso_wound_penalty(), None);
+    }
+}
@@ -30,6 +30,14 @@ impl Rand {
         Self { rng }
     }
 
+    /// the same seed gives the same rolls, for reproducible tests
+    #[cfg(test)]
+    pub fn with_seed(seed: u64) -> Self {
+        use rand::SeedableRng as _; // for seed_from_u64()
+        let rng = Rng::seed_from_u64(seed);
+        Self { rng }
+    }
+
     pub fn random_range<T, R>(&mut self, range: R) -> T
     where
         T: SampleUniform,
@@ -1,6 +1,6 @@
 use std::{cell::RefCell, rc::Rc};
 
-use crate::app::character::{Attribute, Skill, Weapon};
+use crate::app::character::{Attribute, NaturalAttack, RangedWeapon, Skill, Weapon};
 use crate::simulator::rand::Rand;
 
 std::thread_local! {
@@ -27,6 +27,12 @@ impl Roller {
         Self { rand }
     }
 
+    /// make the rolls of this thread reproducible
+    #[cfg(test)]
+    pub fn seed(&self, seed: u64) {
+        *self.rand.borrow_mut() = Rand::with_seed(seed);
+    }
+
     fn roll_die_nonexploding(&self, sides: u8) -> Roll {
         if sides == 0 {
             return Roll(0);
@@ -61,6 +67,22 @@ impl Roller {
         Ok(wild_die.max(old_result))
     }
 
+    /// extras don't have a wild die: on a 1 they roll a d6, another 1 is a crit fail
+    fn check_extra_critical_fail(&self, result: Roll) -> Result<Roll, RollError> {
+        if result.as_u8() < 2 && self.roll_die_nonexploding(6).as_u8() < 2 {
+            return Err(RollError::CriticalFail);
+        }
+        Ok(result)
+    }
+
+    fn apply_wild_die(&self, result: Roll, sides: u8, wild_card: bool) -> Result<Roll, RollError> {
+        if wild_card {
+            self.roll_additional_wild_die(result, sides)
+        } else {
+            self.check_extra_critical_fail(result)
+        }
+    }
+
     pub fn roll_raise(&self) -> Roll {
         self.roll_die(6, 0)
     }
@@ -69,11 +91,26 @@ impl Roller {
         self.roll_die(10, 0)
     }
 
+    /// exploding d4, e.g. for the damage of a throwing knife
+    pub fn roll_d4(&self) -> Roll {
+        self.roll_die(4, 0)
+    }
+
+    /// exploding d6 for each die, e.g. for the damage of a spell
+    pub fn roll_d6s(&self, count: u8) -> Roll {
+        (0..count).fold(Roll(0), |sum, _| sum + self.roll_die(6, 0))
+    }
+
     /// non-exploding 2d6
     pub fn roll_critical_fail_result(&self) -> Roll {
         self.roll_die_nonexploding(6) + self.roll_die_nonexploding(6)
     }
 
+    /// non-exploding d20
+    pub fn roll_hit_location(&self) -> u8 {
+        self.roll_die_nonexploding(20).as_u8()
+    }
+
     pub fn roll_attribute_without_wild_die(&self, attribute: Attribute) -> Roll {
         let (sides, modifier) = match attribute {
             Attribute::W4 => (4, 0),
@@ -88,9 +125,9 @@ impl Roller {
         self.roll_die(sides, modifier)
     }
 
-    pub fn roll_attribute(&self, attribute: Attribute) -> Result<Roll, RollError> {
+    pub fn roll_attribute(&self, attribute: Attribute, wild_card: bool) -> Result<Roll, RollError> {
         let roll = self.roll_attribute_without_wild_die(attribute);
-        self.roll_additional_wild_die(roll, attribute.wild_die_sides())
+        self.apply_wild_die(roll, attribute.wild_die_sides(), wild_card)
     }
 
     pub fn roll_skill_with_n_dice(
@@ -98,6 +135,7 @@ impl Roller {
         skill: Skill,
         n: usize,
         fail_on_one: bool,
+        wild_card: bool,
     ) -> Result<Vec<Roll>, RollError> {
         assert!(n > 0);
         let (sides, modifier) = match skill {
@@ -116,10 +154,16 @@ impl Roller {
             return Err(RollError::Fail);
         }
         let minimum = rolls.iter_mut().min().unwrap();
-        *minimum = self.roll_additional_wild_die(*minimum, skill.wild_die_sides())?;
+        *minimum = self.apply_wild_die(*minimum, skill.wild_die_sides(), wild_card)?;
         Ok(rolls)
     }
 
+    /// ranged weapons roll two dice and don't depend on strength
+    pub fn roll_ranged_damage(&self, weapon: &RangedWeapon) -> Roll {
+        let sides: u8 = weapon.damage.into();
+        self.roll_die(sides, 0) + self.roll_die(sides, 0)
+    }
+
     /// roll weapon damage, but cap die sides by strength die
     pub fn roll_weapon_damage<const SECONDARY: bool>(
         &self,
@@ -132,6 +176,13 @@ impl Roller {
         let modifier = weapon.bonus_damage.into();
         self.roll_die(sides, modifier)
     }
+
+    /// roll damage of a claw, bite etc., also capped by strength die
+    pub fn roll_natural_damage(&self, attack: &NaturalAttack, strength: Attribute) -> Roll {
+        let damage_sides: u8 = attack.damage.into();
+        let strength_sides = strength.into();
+        self.roll_die(damage_sides.min(strength_sides), 0)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]